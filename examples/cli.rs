@@ -1,13 +1,49 @@
 /* ANCHOR: all */
-use clap::Parser;
-use gtk::prelude::GtkWindowExt;
-use relm4::{gtk, ComponentParts, ComponentSender, RelmApp, SimpleComponent};
+use std::path::PathBuf;
 
-struct AppModel {}
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, Subcommand};
+use gtk::prelude::{BoxExt, GtkWindowExt, OrientableExt, WidgetExt};
+use relm4::{gtk, ComponentParts, ComponentSender, RelmApp, RelmWidgetExt, SimpleComponent};
+
+/* ANCHOR: state */
+/// State of the app that can be computed without GTK.
+#[derive(Debug)]
+struct AppState {
+    file: Option<PathBuf>,
+    text: String,
+}
+
+impl AppState {
+    fn load(file: Option<PathBuf>) -> std::io::Result<Self> {
+        let text = match &file {
+            Some(path) => std::fs::read_to_string(path)?,
+            None => String::from("Hello world!"),
+        };
+        Ok(Self { file, text })
+    }
+
+    fn summary(&self) -> String {
+        let file = self
+            .file
+            .as_ref()
+            .map_or_else(|| String::from("(none)"), |path| path.display().to_string());
+        format!(
+            "File: {file}\nLines: {}\nWords: {}",
+            self.text.lines().count(),
+            self.text.split_whitespace().count()
+        )
+    }
+}
+/* ANCHOR_END: state */
+
+struct AppModel {
+    state: AppState,
+}
 
 #[relm4::component]
 impl SimpleComponent for AppModel {
-    type Init = ();
+    type Init = AppState;
     type Input = ();
     type Output = ();
 
@@ -15,20 +51,38 @@ impl SimpleComponent for AppModel {
         gtk::Window {
             set_title: Some("Hello world with CLI"),
             set_default_width: 300,
-            set_default_height: 100,
+            set_default_height: 200,
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+                set_spacing: 5,
+                set_margin_all: 5,
 
-            gtk::Label {
-                set_label: "Hello world!",
+                gtk::Label {
+                    set_label: &model.state.summary(),
+                    set_xalign: 0.0,
+                },
+
+                gtk::ScrolledWindow {
+                    set_vexpand: true,
+
+                    gtk::Label {
+                        set_label: &model.state.text,
+                        set_selectable: true,
+                        set_xalign: 0.0,
+                        set_yalign: 0.0,
+                    }
+                }
             }
         }
     }
 
     fn init(
-        _init: Self::Init,
+        state: Self::Init,
         root: Self::Root,
         _sender: ComponentSender<Self>,
     ) -> ComponentParts<Self> {
-        let model = AppModel {};
+        let model = AppModel { state };
         let widgets = view_output!();
 
         ComponentParts { model, widgets }
@@ -39,27 +93,103 @@ impl SimpleComponent for AppModel {
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 struct Args {
-    /// some argument to test
-    #[arg(long)]
-    non_gtk_arg: bool,
+    /// File to load on startup
+    #[arg(long, global = true, value_name = "FILE")]
+    open: Option<PathBuf>,
 
-    /// Unknown arguments or everything after -- gets passed through to GTK.
-    #[arg(allow_hyphen_values = true, trailing_var_arg = true)]
-    gtk_options: Vec<String>,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Open the window (default)
+    Run {
+        /// Unknown arguments or everything after -- gets passed through to GTK.
+        #[arg(allow_hyphen_values = true, trailing_var_arg = true)]
+        gtk_options: Vec<String>,
+    },
+    /// Print the app state without initializing GTK
+    Print,
 }
 /* ANCHOR_END: args_struct */
 
+/* ANCHOR: validate */
+/// Options understood by [`gtk::gio::Application`].
+/// GTK 4 doesn't add any options of its own.
+const GTK_OPTIONS: &[&str] = &[
+    "-h",
+    "--help",
+    "--help-all",
+    "--help-gapplication",
+    "--gapplication-service",
+];
+
+fn validate_gtk_options(options: &[String]) {
+    // Everything after the first GTK option is passed to GTK,
+    // so our own options would be swallowed if they come last
+    let own_options: Vec<String> = Args::command()
+        .get_arguments()
+        .filter_map(|arg| arg.get_long())
+        .map(|long| format!("--{long}"))
+        .collect();
+    if let Some(own) = options.iter().find_map(|option| {
+        let name = option.split('=').next().unwrap_or(option);
+        own_options.iter().find(|own| *own == name)
+    }) {
+        Args::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                format!("'{own}' must come before the GTK options"),
+            )
+            .exit();
+    }
+
+    if let Some(invalid) = options
+        .iter()
+        .find(|option| !GTK_OPTIONS.contains(&option.as_str()))
+    {
+        Args::command()
+            .error(
+                ErrorKind::UnknownArgument,
+                format!("unexpected GTK option '{invalid}'"),
+            )
+            .exit();
+    }
+}
+/* ANCHOR_END: validate */
+
 fn main() {
     let args = Args::parse();
-    dbg!(&args);
 
-    /* ANCHOR: main */
-    let program_invocation = std::env::args().next().unwrap();
-    let mut gtk_args = vec![program_invocation];
-    gtk_args.extend(args.gtk_options.clone());
+    /* ANCHOR: load */
+    let state = match AppState::load(args.open) {
+        Ok(state) => state,
+        Err(err) => Args::command()
+            .error(ErrorKind::Io, format!("could not read file: {err}"))
+            .exit(),
+    };
+    /* ANCHOR_END: load */
+
+    /* ANCHOR: subcommands */
+    let command = args.command.unwrap_or(Command::Run {
+        gtk_options: Vec::new(),
+    });
+
+    match command {
+        // GTK is never initialized here, so this works without a display
+        Command::Print => println!("{}", state.summary()),
+        Command::Run { gtk_options } => {
+            validate_gtk_options(&gtk_options);
 
-    let app = RelmApp::new("relm4.test.helloworld_cli");
-    app.with_args(gtk_args).run::<AppModel>(());
-    /* ANCHOR_END: main */
+            let program_invocation = std::env::args().next().unwrap();
+            let mut gtk_args = vec![program_invocation];
+            gtk_args.extend(gtk_options);
+
+            let app = RelmApp::new("relm4.test.helloworld_cli");
+            app.with_args(gtk_args).run::<AppModel>(state);
+        }
+    }
+    /* ANCHOR_END: subcommands */
 }
 /* ANCHOR_END: all */
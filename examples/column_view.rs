@@ -0,0 +1,338 @@
+// ANCHOR: all
+use gtk::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use relm4::actions::{RelmAction, RelmActionGroup};
+use relm4::typed_view::column::{LabelColumn, TypedColumnView};
+use relm4::{
+    gtk, Component, ComponentController, ComponentParts, ComponentSender, Controller, RelmApp,
+    RelmWidgetExt, SimpleComponent,
+};
+
+const RECORD_COUNT: u32 = 10_000;
+const EXTENSIONS: [&str; 6] = ["txt", "png", "rs", "toml", "pdf", "mp3"];
+
+// ANCHOR: record
+/// A single row of the table.
+#[derive(Debug, Clone)]
+struct FileRecord {
+    id: u32,
+    name: String,
+    kind: &'static str,
+    size: u64,
+    modified_days_ago: u32,
+}
+
+impl FileRecord {
+    /// Generates the same "random" files on every run.
+    fn generate(count: u32) -> Vec<Self> {
+        let mut rng = StdRng::seed_from_u64(42);
+        (0..count)
+            .map(|id| {
+                let kind = EXTENSIONS[rng.random_range(0..EXTENSIONS.len())];
+                Self {
+                    id,
+                    name: format!("file-{id:05}.{kind}"),
+                    kind,
+                    size: rng.random_range(0..50_000_000),
+                    modified_days_ago: rng.random_range(0..1000),
+                }
+            })
+            .collect()
+    }
+}
+// ANCHOR_END: record
+
+// ANCHOR: columns
+struct NameColumn;
+
+impl LabelColumn for NameColumn {
+    type Item = FileRecord;
+    type Value = String;
+
+    const COLUMN_NAME: &'static str = "Name";
+    const ENABLE_SORT: bool = true;
+    const ENABLE_EXPAND: bool = true;
+
+    fn get_cell_value(item: &Self::Item) -> Self::Value {
+        item.name.clone()
+    }
+}
+
+struct KindColumn;
+
+impl LabelColumn for KindColumn {
+    type Item = FileRecord;
+    type Value = &'static str;
+
+    const COLUMN_NAME: &'static str = "Kind";
+    const ENABLE_SORT: bool = true;
+
+    fn get_cell_value(item: &Self::Item) -> Self::Value {
+        item.kind
+    }
+}
+
+struct SizeColumn;
+
+impl LabelColumn for SizeColumn {
+    type Item = FileRecord;
+    // Sorting uses the value, so sizes are compared as numbers, not as text
+    type Value = u64;
+
+    const COLUMN_NAME: &'static str = "Size";
+    const ENABLE_SORT: bool = true;
+
+    fn get_cell_value(item: &Self::Item) -> Self::Value {
+        item.size
+    }
+
+    fn format_cell_value(value: &Self::Value) -> String {
+        gtk::glib::format_size(*value).into()
+    }
+}
+
+struct ModifiedColumn;
+
+impl LabelColumn for ModifiedColumn {
+    type Item = FileRecord;
+    type Value = u32;
+
+    const COLUMN_NAME: &'static str = "Modified";
+    const ENABLE_SORT: bool = true;
+
+    fn get_cell_value(item: &Self::Item) -> Self::Value {
+        item.modified_days_ago
+    }
+
+    fn format_cell_value(value: &Self::Value) -> String {
+        match value {
+            0 => "Today".into(),
+            1 => "Yesterday".into(),
+            days => format!("{days} days ago"),
+        }
+    }
+}
+// ANCHOR_END: columns
+
+// ANCHOR: actions
+relm4::new_action_group!(TableActionGroup, "table");
+relm4::new_stateless_action!(CopyAction, TableActionGroup, "copy");
+relm4::new_stateless_action!(SelectAllAction, TableActionGroup, "select-all");
+// ANCHOR_END: actions
+
+// ANCHOR: table_model
+struct FileTable {
+    view_wrapper: TypedColumnView<FileRecord, gtk::MultiSelection>,
+}
+
+#[derive(Debug)]
+enum FileTableMsg {
+    SelectionChanged,
+    SelectAll,
+    CopySelection,
+}
+
+#[derive(Debug)]
+enum FileTableOutput {
+    /// The ids of all selected files.
+    Selected(Vec<u32>),
+}
+// ANCHOR_END: table_model
+
+// ANCHOR: selection
+impl FileTable {
+    /// Returns the selected records in the order they are shown.
+    fn selected_records(&self) -> Vec<FileRecord> {
+        // Positions refer to the sorted model that's shown, not to the order of insertion
+        let selection = self.view_wrapper.selection_model.selection();
+        (0..selection.size())
+            .filter_map(|nth| self.view_wrapper.get_visible(selection.nth(nth as u32)))
+            .map(|item| item.borrow().clone())
+            .collect()
+    }
+}
+// ANCHOR_END: selection
+
+#[relm4::component]
+impl Component for FileTable {
+    type Init = Vec<FileRecord>;
+    type Input = FileTableMsg;
+    type Output = FileTableOutput;
+    type CommandOutput = ();
+
+    view! {
+        gtk::ScrolledWindow {
+            set_vexpand: true,
+
+            #[local_ref]
+            column_view -> gtk::ColumnView {
+                set_show_row_separators: true,
+                set_show_column_separators: true,
+            }
+        }
+    }
+
+    menu! {
+        context_menu: {
+            "Copy names" => CopyAction,
+            "Select all" => SelectAllAction,
+        }
+    }
+
+    // ANCHOR: table_init
+    fn init(
+        records: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let mut view_wrapper = TypedColumnView::<FileRecord, gtk::MultiSelection>::new();
+        view_wrapper.append_column::<NameColumn>();
+        view_wrapper.append_column::<KindColumn>();
+        view_wrapper.append_column::<SizeColumn>();
+        view_wrapper.append_column::<ModifiedColumn>();
+        view_wrapper.extend_from_iter(records);
+
+        // Selection changes are reported as input messages
+        view_wrapper.selection_model.connect_selection_changed({
+            let sender = sender.clone();
+            move |_, _, _| {
+                sender.input(FileTableMsg::SelectionChanged);
+            }
+        });
+
+        let model = FileTable { view_wrapper };
+        let column_view = &model.view_wrapper.view;
+        let widgets = view_output!();
+
+        // Show the context menu where the user right-clicked
+        let popover = gtk::PopoverMenu::from_model(Some(&context_menu));
+        popover.set_parent(column_view);
+        popover.set_has_arrow(false);
+        popover.set_halign(gtk::Align::Start);
+
+        let click = gtk::GestureClick::builder()
+            .button(gtk::gdk::BUTTON_SECONDARY)
+            .build();
+        click.connect_pressed(move |_, _, x, y| {
+            popover.set_pointing_to(Some(&gtk::gdk::Rectangle::new(x as i32, y as i32, 1, 1)));
+            popover.popup();
+        });
+        column_view.add_controller(click);
+
+        let copy: RelmAction<CopyAction> = {
+            let sender = sender.clone();
+            RelmAction::new_stateless(move |_| {
+                sender.input(FileTableMsg::CopySelection);
+            })
+        };
+        let select_all: RelmAction<SelectAllAction> = RelmAction::new_stateless(move |_| {
+            sender.input(FileTableMsg::SelectAll);
+        });
+
+        let mut group = RelmActionGroup::<TableActionGroup>::new();
+        group.add_action(copy);
+        group.add_action(select_all);
+        group.register_for_widget(column_view);
+
+        ComponentParts { model, widgets }
+    }
+    // ANCHOR_END: table_init
+
+    // ANCHOR: table_update
+    fn update(&mut self, msg: Self::Input, sender: ComponentSender<Self>, root: &Self::Root) {
+        match msg {
+            FileTableMsg::SelectionChanged => {
+                let ids = self
+                    .selected_records()
+                    .iter()
+                    .map(|record| record.id)
+                    .collect();
+                sender.output(FileTableOutput::Selected(ids)).unwrap();
+            }
+            FileTableMsg::SelectAll => {
+                self.view_wrapper.selection_model.select_all();
+            }
+            FileTableMsg::CopySelection => {
+                let names: Vec<String> = self
+                    .selected_records()
+                    .into_iter()
+                    .map(|record| record.name)
+                    .collect();
+                root.clipboard().set_text(&names.join("\n"));
+            }
+        }
+    }
+    // ANCHOR_END: table_update
+}
+
+// ANCHOR: app
+struct App {
+    table: Controller<FileTable>,
+    selected: Vec<u32>,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    Selected(Vec<u32>),
+}
+
+#[relm4::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = AppMsg;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            set_title: Some("Column view"),
+            set_default_size: (600, 500),
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+
+                append: model.table.widget(),
+
+                gtk::Label {
+                    set_margin_all: 5,
+                    set_xalign: 0.0,
+                    #[watch]
+                    set_label: &match model.selected.as_slice() {
+                        [] => format!("{RECORD_COUNT} files"),
+                        [id] => format!("Selected file {id}"),
+                        ids => format!("{} of {RECORD_COUNT} files selected", ids.len()),
+                    },
+                },
+            }
+        }
+    }
+
+    fn init(_: (), root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+        let table = FileTable::builder()
+            .launch(FileRecord::generate(RECORD_COUNT))
+            .forward(sender.input_sender(), |output| match output {
+                FileTableOutput::Selected(ids) => AppMsg::Selected(ids),
+            });
+
+        let model = App {
+            table,
+            selected: Vec::new(),
+        };
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            AppMsg::Selected(ids) => self.selected = ids,
+        }
+    }
+}
+// ANCHOR_END: app
+
+fn main() {
+    let app = RelmApp::new("relm4.example.column_view");
+    app.run::<App>(());
+}
+// ANCHOR_END: all
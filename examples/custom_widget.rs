@@ -0,0 +1,246 @@
+// ANCHOR: all
+use gtk::prelude::{BoxExt, ButtonExt, GtkWindowExt, OrientableExt, WidgetExt};
+use relm4::{gtk, ComponentParts, ComponentSender, RelmApp, RelmWidgetExt, SimpleComponent};
+
+use counter_widget::CounterWidget;
+
+// ANCHOR: counter_widget
+mod counter_widget {
+    use gtk::glib;
+    use gtk::prelude::*;
+    use relm4::gtk;
+
+    pub use imp::MAX_VALUE;
+
+    // ANCHOR: wrapper
+    glib::wrapper! {
+        /// A widget with two buttons that change a number.
+        pub struct CounterWidget(ObjectSubclass<imp::CounterWidget>)
+            @extends gtk::Widget,
+            @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+    }
+
+    impl CounterWidget {
+        pub fn new() -> Self {
+            glib::Object::new()
+        }
+
+        /// Called when the user changes the value with one of the buttons.
+        ///
+        /// Unlike `connect_value_notify`, this isn't called for `set_value`,
+        /// so updating the widget from the model doesn't send a message back.
+        pub fn connect_value_changed<F: Fn(&Self, u32) + 'static>(
+            &self,
+            f: F,
+        ) -> glib::SignalHandlerId {
+            self.connect_closure(
+                "value-changed",
+                false,
+                glib::closure_local!(move |widget: &CounterWidget, value: u32| f(widget, value)),
+            )
+        }
+    }
+
+    // The `view!` macro creates widgets with `Default::default()`
+    impl Default for CounterWidget {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    // ANCHOR_END: wrapper
+
+    // ANCHOR: imp
+    mod imp {
+        use std::cell::{Cell, OnceCell};
+        use std::sync::OnceLock;
+
+        use gtk::glib::subclass::Signal;
+        use gtk::glib::{self, clone, Properties};
+        use gtk::prelude::*;
+        use gtk::subclass::prelude::*;
+        use relm4::gtk;
+
+        pub const MAX_VALUE: u32 = 100;
+
+        #[derive(Debug, Default, Properties)]
+        #[properties(wrapper_type = super::CounterWidget)]
+        pub struct CounterWidget {
+            // The setter only notifies about real changes, so updates
+            // that set the same value don't wake up any handlers
+            #[property(get, set = Self::set_value, maximum = MAX_VALUE, explicit_notify)]
+            value: Cell<u32>,
+            label: OnceCell<gtk::Label>,
+        }
+
+        #[glib::object_subclass]
+        impl ObjectSubclass for CounterWidget {
+            const NAME: &'static str = "RelmCounterWidget";
+            type Type = super::CounterWidget;
+            type ParentType = gtk::Widget;
+
+            fn class_init(klass: &mut Self::Class) {
+                klass.set_layout_manager_type::<gtk::BoxLayout>();
+                klass.set_css_name("counter");
+            }
+        }
+
+        #[glib::derived_properties]
+        impl ObjectImpl for CounterWidget {
+            fn constructed(&self) {
+                self.parent_constructed();
+                let obj = self.obj();
+
+                if let Some(layout) = obj.layout_manager().and_downcast::<gtk::BoxLayout>() {
+                    layout.set_spacing(5);
+                }
+
+                let decrement = gtk::Button::from_icon_name("list-remove-symbolic");
+                let label = gtk::Label::builder().label("0").width_chars(3).build();
+                let increment = gtk::Button::from_icon_name("list-add-symbolic");
+
+                decrement.set_parent(&*obj);
+                label.set_parent(&*obj);
+                increment.set_parent(&*obj);
+
+                decrement.connect_clicked(clone!(
+                    #[weak]
+                    obj,
+                    move |_| obj.imp().change_by(-1)
+                ));
+                increment.connect_clicked(clone!(
+                    #[weak]
+                    obj,
+                    move |_| obj.imp().change_by(1)
+                ));
+
+                self.label.set(label).unwrap();
+            }
+
+            fn dispose(&self) {
+                while let Some(child) = self.obj().first_child() {
+                    child.unparent();
+                }
+            }
+
+            fn signals() -> &'static [Signal] {
+                static SIGNALS: OnceLock<Vec<Signal>> = OnceLock::new();
+                SIGNALS.get_or_init(|| {
+                    vec![Signal::builder("value-changed")
+                        .param_types([u32::static_type()])
+                        .build()]
+                })
+            }
+        }
+
+        impl WidgetImpl for CounterWidget {}
+
+        impl CounterWidget {
+            fn set_value(&self, value: u32) {
+                let value = value.min(MAX_VALUE);
+                if self.value.replace(value) != value {
+                    if let Some(label) = self.label.get() {
+                        label.set_label(&value.to_string());
+                    }
+                    self.obj().notify_value();
+                }
+            }
+
+            fn change_by(&self, delta: i32) {
+                let old = self.value.get();
+                let new = old.saturating_add_signed(delta).min(MAX_VALUE);
+                if new != old {
+                    let obj = self.obj();
+                    obj.set_value(new);
+                    obj.emit_by_name::<()>("value-changed", &[&new]);
+                }
+            }
+        }
+    }
+    // ANCHOR_END: imp
+}
+// ANCHOR_END: counter_widget
+
+struct AppModel {
+    value: u32,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    SetValue(u32),
+    Reset,
+    Double,
+}
+
+// ANCHOR: component
+#[relm4::component]
+impl SimpleComponent for AppModel {
+    type Init = u32;
+    type Input = AppMsg;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            set_title: Some("Custom widget"),
+            set_default_width: 300,
+            set_default_height: 100,
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+                set_spacing: 5,
+                set_margin_all: 5,
+
+                // Custom widgets are used like any other widget
+                CounterWidget {
+                    set_halign: gtk::Align::Center,
+                    #[watch]
+                    set_value: model.value,
+                    connect_value_changed[sender] => move |_, value| {
+                        sender.input(AppMsg::SetValue(value));
+                    },
+                },
+
+                gtk::Label {
+                    #[watch]
+                    set_label: &format!("Value in the model: {}", model.value),
+                },
+
+                gtk::Button {
+                    set_label: "Double",
+                    connect_clicked => AppMsg::Double,
+                },
+
+                gtk::Button {
+                    set_label: "Reset",
+                    connect_clicked => AppMsg::Reset,
+                },
+            }
+        }
+    }
+    // ANCHOR_END: component
+
+    fn init(
+        value: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = AppModel { value };
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            AppMsg::SetValue(value) => self.value = value,
+            // The widget limits the value, so the model has to do the same
+            AppMsg::Double => self.value = (self.value * 2).min(counter_widget::MAX_VALUE),
+            AppMsg::Reset => self.value = 0,
+        }
+    }
+}
+
+fn main() {
+    let app = RelmApp::new("relm4.example.custom_widget");
+    app.run::<AppModel>(5);
+}
+// ANCHOR_END: all
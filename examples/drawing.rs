@@ -0,0 +1,308 @@
+// ANCHOR: all
+use std::fs::File;
+use std::io::Write;
+
+use gtk::cairo::{self, Context, ImageSurface};
+use gtk::prelude::*;
+use relm4::abstractions::DrawHandler;
+use relm4::{gtk, ComponentParts, ComponentSender, RelmApp, RelmWidgetExt, SimpleComponent};
+
+const EXPORT_PATH: &str = "drawing.png";
+
+// ANCHOR: stroke
+/// Colors the user can pick from.
+const COLORS: [(&str, (f64, f64, f64)); 4] = [
+    ("Black", (0.0, 0.0, 0.0)),
+    ("Red", (1.0, 0.0, 0.0)),
+    ("Green", (0.0, 0.6, 0.0)),
+    ("Blue", (0.0, 0.0, 1.0)),
+];
+
+/// A line drawn with a single drag of the mouse.
+#[derive(Debug, Clone)]
+struct Stroke {
+    color: (f64, f64, f64),
+    width: f64,
+    points: Vec<(f64, f64)>,
+}
+// ANCHOR_END: stroke
+
+// ANCHOR: render
+/// Draws all strokes on a white background.
+///
+/// This works with any cairo context, so the same code is used
+/// for the drawing area and for exporting the image.
+fn render(cx: &Context, strokes: &[Stroke]) -> Result<(), cairo::Error> {
+    cx.set_source_rgb(1.0, 1.0, 1.0);
+    cx.paint()?;
+
+    cx.set_line_cap(cairo::LineCap::Round);
+    cx.set_line_join(cairo::LineJoin::Round);
+    for stroke in strokes {
+        let Some(&(x, y)) = stroke.points.first() else {
+            continue;
+        };
+        let (r, g, b) = stroke.color;
+        cx.set_source_rgb(r, g, b);
+        cx.set_line_width(stroke.width);
+        cx.move_to(x, y);
+        // A single point is drawn as a dot
+        cx.line_to(x, y);
+        for &(x, y) in &stroke.points[1..] {
+            cx.line_to(x, y);
+        }
+        cx.stroke()?;
+    }
+    Ok(())
+}
+
+/// Renders the strokes into an image in memory, no GPU or display required.
+fn render_image(strokes: &[Stroke], width: i32, height: i32) -> Result<ImageSurface, cairo::Error> {
+    let surface = ImageSurface::create(cairo::Format::ARgb32, width, height)?;
+    render(&Context::new(&surface)?, strokes)?;
+    Ok(surface)
+}
+
+/// Fails if the canvas has no size yet, because a PNG can't be empty.
+fn export_png(
+    strokes: &[Stroke],
+    width: i32,
+    height: i32,
+    writer: &mut impl Write,
+) -> Result<(), cairo::IoError> {
+    if width <= 0 || height <= 0 {
+        return Err(cairo::Error::InvalidSize.into());
+    }
+    render_image(strokes, width, height)?.write_to_png(writer)
+}
+// ANCHOR_END: render
+
+// ANCHOR: model
+struct App {
+    handler: DrawHandler,
+    strokes: Vec<Stroke>,
+    color: (f64, f64, f64),
+    line_width: f64,
+    size: (i32, i32),
+    status: String,
+}
+
+#[derive(Debug)]
+enum Msg {
+    StrokeBegin(f64, f64),
+    StrokeMove(f64, f64),
+    SetColor(u32),
+    SetLineWidth(f64),
+    Resize(i32, i32),
+    Undo,
+    Clear,
+    Export,
+}
+// ANCHOR_END: model
+
+#[relm4::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = Msg;
+    type Output = ();
+
+    // ANCHOR: view
+    view! {
+        gtk::Window {
+            set_title: Some("Drawing"),
+            set_default_size: (600, 500),
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+
+                gtk::Box {
+                    set_orientation: gtk::Orientation::Horizontal,
+                    set_spacing: 5,
+                    set_margin_all: 5,
+
+                    gtk::DropDown::from_strings(&COLORS.map(|(name, _)| name)) {
+                        connect_selected_notify[sender] => move |dropdown| {
+                            sender.input(Msg::SetColor(dropdown.selected()));
+                        },
+                    },
+
+                    gtk::SpinButton::with_range(1.0, 50.0, 1.0) {
+                        set_value: model.line_width,
+                        set_tooltip_text: Some("Line width"),
+                        connect_value_changed[sender] => move |spin| {
+                            sender.input(Msg::SetLineWidth(spin.value()));
+                        },
+                    },
+
+                    gtk::Button {
+                        set_label: "Undo",
+                        #[watch]
+                        set_sensitive: !model.strokes.is_empty(),
+                        connect_clicked => Msg::Undo,
+                    },
+
+                    gtk::Button {
+                        set_label: "Clear",
+                        #[watch]
+                        set_sensitive: !model.strokes.is_empty(),
+                        connect_clicked => Msg::Clear,
+                    },
+
+                    gtk::Button {
+                        set_label: "Export",
+                        connect_clicked => Msg::Export,
+                    },
+
+                    gtk::Label {
+                        set_hexpand: true,
+                        set_xalign: 1.0,
+                        #[watch]
+                        set_label: &model.status,
+                    },
+                },
+
+                #[local_ref]
+                area -> gtk::DrawingArea {
+                    set_vexpand: true,
+                    set_hexpand: true,
+
+                    add_controller = gtk::GestureDrag {
+                        connect_drag_begin[sender] => move |_, x, y| {
+                            sender.input(Msg::StrokeBegin(x, y));
+                        },
+                        // The offset is relative to the start of the drag
+                        connect_drag_update[sender] => move |drag, offset_x, offset_y| {
+                            if let Some((x, y)) = drag.start_point() {
+                                sender.input(Msg::StrokeMove(x + offset_x, y + offset_y));
+                            }
+                        },
+                    },
+
+                    connect_resize[sender] => move |_, width, height| {
+                        sender.input(Msg::Resize(width, height));
+                    },
+                },
+            },
+        }
+    }
+    // ANCHOR_END: view
+
+    fn init(_: (), root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+        let model = App {
+            handler: DrawHandler::new(),
+            strokes: Vec::new(),
+            color: COLORS[0].1,
+            line_width: 5.0,
+            size: (0, 0),
+            status: String::new(),
+        };
+
+        let area = model.handler.drawing_area();
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    // ANCHOR: update
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            Msg::StrokeBegin(x, y) => self.strokes.push(Stroke {
+                color: self.color,
+                width: self.line_width,
+                points: vec![(x, y)],
+            }),
+            Msg::StrokeMove(x, y) => {
+                if let Some(stroke) = self.strokes.last_mut() {
+                    stroke.points.push((x, y));
+                }
+            }
+            Msg::SetColor(index) => {
+                if let Some((_, color)) = COLORS.get(index as usize) {
+                    self.color = *color;
+                }
+            }
+            Msg::SetLineWidth(width) => self.line_width = width,
+            Msg::Resize(width, height) => self.size = (width, height),
+            Msg::Undo => {
+                self.strokes.pop();
+            }
+            Msg::Clear => self.strokes.clear(),
+            // The canvas gets its size when it's shown for the first time
+            Msg::Export if self.size.0 <= 0 || self.size.1 <= 0 => {
+                self.status = String::from("Nothing to export yet");
+            }
+            Msg::Export => {
+                let (width, height) = self.size;
+                let result = File::create(EXPORT_PATH)
+                    .map_err(cairo::IoError::from)
+                    .and_then(|mut file| export_png(&self.strokes, width, height, &mut file));
+                self.status = match result {
+                    Ok(()) => format!("Saved {EXPORT_PATH}"),
+                    Err(err) => format!("Export failed: {err}"),
+                };
+            }
+        }
+
+        // The whole canvas is drawn again after each change
+        let cx = self.handler.get_context();
+        if let Err(err) = render(&cx, &self.strokes) {
+            self.status = format!("Drawing failed: {err}");
+        }
+    }
+    // ANCHOR_END: update
+}
+
+fn main() {
+    let app = RelmApp::new("relm4.example.drawing");
+    app.run::<App>(());
+}
+// ANCHOR_END: all
+
+// ANCHOR: test
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reads a pixel of an ARGB32 image.
+    fn pixel(surface: &mut ImageSurface, x: usize, y: usize) -> u32 {
+        let stride = surface.stride() as usize;
+        let data = surface.data().unwrap();
+        let offset = y * stride + x * 4;
+        u32::from_ne_bytes(data[offset..offset + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn exported_png_shows_strokes() {
+        let strokes = [Stroke {
+            color: (1.0, 0.0, 0.0),
+            width: 10.0,
+            points: vec![(10.0, 50.0), (90.0, 50.0)],
+        }];
+
+        let mut png = Vec::new();
+        export_png(&strokes, 120, 100, &mut png).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+        let mut exported = ImageSurface::create_from_png(&mut png.as_slice()).unwrap();
+
+        assert_eq!((exported.width(), exported.height()), (120, 100));
+        // Opaque red on the stroke, which covers y = 45 to 55 and reaches
+        // from x = 5 to 95 because of the round caps
+        for x in [10, 50, 90] {
+            assert_eq!(pixel(&mut exported, x, 50), 0xFFFF_0000, "pixel ({x}, 50)");
+        }
+        assert_eq!(pixel(&mut exported, 50, 46), 0xFFFF_0000);
+        assert_eq!(pixel(&mut exported, 50, 53), 0xFFFF_0000);
+        // Opaque white everywhere else, including the part that's wider than the stroke
+        for (x, y) in [(0, 0), (50, 10), (50, 40), (50, 60), (110, 50), (119, 99)] {
+            assert_eq!(pixel(&mut exported, x, y), 0xFFFF_FFFF, "pixel ({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn empty_canvas_is_not_exported() {
+        let mut png = Vec::new();
+        assert!(export_png(&[], 0, 0, &mut png).is_err());
+        assert!(png.is_empty());
+    }
+}
+// ANCHOR_END: test
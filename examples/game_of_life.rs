@@ -0,0 +1,378 @@
+// ANCHOR: all
+use std::time::{Duration, Instant};
+
+use gtk::glib;
+use gtk::prelude::*;
+use rand::Rng;
+use relm4::abstractions::DrawHandler;
+use relm4::factory::positions::GridPosition;
+use relm4::factory::{DynamicIndex, FactoryComponent, FactorySender, FactoryVecDeque, Position};
+use relm4::{gtk, ComponentParts, ComponentSender, RelmApp, RelmWidgetExt, SimpleComponent};
+
+const WIDTH: usize = 100;
+const HEIGHT: usize = 100;
+const CELL_SIZE: i32 = 6;
+/// Time between two generations, which limits the game to about 60 generations per second.
+const TICK: Duration = Duration::from_millis(16);
+
+// ANCHOR: board
+/// The game logic, shared by both implementations.
+struct Board {
+    cells: Vec<bool>,
+}
+
+impl Board {
+    fn random() -> Self {
+        let mut rng = rand::rng();
+        Self {
+            cells: (0..WIDTH * HEIGHT).map(|_| rng.random_bool(0.3)).collect(),
+        }
+    }
+
+    /// Counts the living neighbors, the edges wrap around.
+    fn neighbors(&self, index: usize) -> usize {
+        let (x, y) = (index % WIDTH, index / WIDTH);
+        let mut count = 0;
+        for dy in [HEIGHT - 1, 0, 1] {
+            for dx in [WIDTH - 1, 0, 1] {
+                if (dx, dy) != (0, 0) {
+                    let neighbor = (y + dy) % HEIGHT * WIDTH + (x + dx) % WIDTH;
+                    count += usize::from(self.cells[neighbor]);
+                }
+            }
+        }
+        count
+    }
+
+    /// Computes the next generation and returns the indices of all cells that changed.
+    fn step(&mut self) -> Vec<usize> {
+        let mut changed = Vec::new();
+        let next = (0..self.cells.len())
+            .map(|index| {
+                let alive = self.cells[index];
+                let next = matches!((alive, self.neighbors(index)), (true, 2 | 3) | (false, 3));
+                if next != alive {
+                    changed.push(index);
+                }
+                next
+            })
+            .collect();
+        self.cells = next;
+        changed
+    }
+}
+// ANCHOR_END: board
+
+// ANCHOR: ticker
+#[derive(Debug)]
+enum LifeMsg {
+    Tick,
+    TogglePause,
+    Randomize,
+}
+
+/// Sends a tick message at a fixed interval until the component is shut down.
+fn start_ticker(sender: relm4::Sender<LifeMsg>) {
+    glib::timeout_add_local(TICK, move || {
+        if sender.send(LifeMsg::Tick).is_ok() {
+            glib::ControlFlow::Continue
+        } else {
+            glib::ControlFlow::Break
+        }
+    });
+}
+
+/// Counts the generations that were computed during the last second.
+///
+/// This isn't the frame rate: GTK draws at most one frame per refresh of
+/// the display, no matter how many generations were computed in between.
+struct GenerationCounter {
+    generations: u32,
+    since: Instant,
+    per_second: f64,
+}
+
+impl GenerationCounter {
+    fn new() -> Self {
+        Self {
+            generations: 0,
+            since: Instant::now(),
+            per_second: 0.0,
+        }
+    }
+
+    fn generation(&mut self) {
+        self.generations += 1;
+        let elapsed = self.since.elapsed();
+        if elapsed >= Duration::from_secs(1) {
+            self.per_second = f64::from(self.generations) / elapsed.as_secs_f64();
+            self.generations = 0;
+            self.since = Instant::now();
+        }
+    }
+}
+// ANCHOR_END: ticker
+
+// ANCHOR: factory_cell
+/// A single cell of the grid. Only cells that change are updated.
+struct Cell {
+    alive: bool,
+}
+
+#[relm4::factory]
+impl FactoryComponent for Cell {
+    type Init = bool;
+    type Input = ();
+    type Output = ();
+    type CommandOutput = ();
+    type ParentWidget = gtk::Grid;
+
+    view! {
+        #[root]
+        gtk::Box {
+            set_size_request: (CELL_SIZE, CELL_SIZE),
+            add_css_class: "life-cell",
+            #[watch]
+            set_class_active: ("alive", self.alive),
+        }
+    }
+
+    fn init_model(alive: Self::Init, _index: &DynamicIndex, _sender: FactorySender<Self>) -> Self {
+        Self { alive }
+    }
+}
+
+impl Position<GridPosition, DynamicIndex> for Cell {
+    fn position(&self, index: &DynamicIndex) -> GridPosition {
+        let index = index.current_index();
+        GridPosition {
+            column: (index % WIDTH) as i32,
+            row: (index / WIDTH) as i32,
+            width: 1,
+            height: 1,
+        }
+    }
+}
+// ANCHOR_END: factory_cell
+
+// ANCHOR: factory_app
+struct FactoryApp {
+    board: Board,
+    cells: FactoryVecDeque<Cell>,
+    running: bool,
+    generations: GenerationCounter,
+}
+
+impl FactoryApp {
+    /// Replaces all cells, for example after randomizing the board.
+    fn reset_cells(&mut self) {
+        let mut guard = self.cells.guard();
+        guard.clear();
+        for &alive in &self.board.cells {
+            guard.push_back(alive);
+        }
+    }
+}
+
+#[relm4::component]
+impl SimpleComponent for FactoryApp {
+    type Init = ();
+    type Input = LifeMsg;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            set_title: Some("Game of Life (factory)"),
+            set_resizable: false,
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+                set_spacing: 5,
+                set_margin_all: 5,
+
+                gtk::Box {
+                    set_spacing: 5,
+
+                    gtk::Button {
+                        #[watch]
+                        set_label: if model.running { "Pause" } else { "Play" },
+                        connect_clicked => LifeMsg::TogglePause,
+                    },
+                    gtk::Button {
+                        set_label: "Randomize",
+                        connect_clicked => LifeMsg::Randomize,
+                    },
+                    gtk::Label {
+                        #[watch]
+                        set_label: &format!("{:.0} generations/s", model.generations.per_second),
+                    },
+                },
+
+                #[local_ref]
+                grid -> gtk::Grid {},
+            },
+        }
+    }
+
+    fn init(_: (), root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+        relm4::set_global_css(".life-cell { background: white; } .life-cell.alive { background: black; }");
+
+        let mut model = FactoryApp {
+            board: Board::random(),
+            cells: FactoryVecDeque::builder().launch_default().detach(),
+            running: true,
+            generations: GenerationCounter::new(),
+        };
+        model.reset_cells();
+
+        let grid = model.cells.widget();
+        let widgets = view_output!();
+
+        start_ticker(sender.input_sender().clone());
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            LifeMsg::Tick => {
+                if !self.running {
+                    return;
+                }
+                let changed = self.board.step();
+                // Only the changed cells are marked for an update
+                let mut guard = self.cells.guard();
+                for index in changed {
+                    if let Some(cell) = guard.get_mut(index) {
+                        cell.alive = self.board.cells[index];
+                    }
+                }
+                self.generations.generation();
+            }
+            LifeMsg::TogglePause => self.running = !self.running,
+            LifeMsg::Randomize => {
+                self.board = Board::random();
+                self.reset_cells();
+            }
+        }
+    }
+}
+// ANCHOR_END: factory_app
+
+// ANCHOR: canvas_app
+struct CanvasApp {
+    board: Board,
+    handler: DrawHandler,
+    running: bool,
+    generations: GenerationCounter,
+}
+
+impl CanvasApp {
+    /// Draws the whole board, there's no benefit in drawing single cells.
+    fn draw(&mut self) {
+        let cx = self.handler.get_context();
+        cx.set_source_rgb(1.0, 1.0, 1.0);
+        cx.paint().expect("Couldn't fill the canvas");
+
+        cx.set_source_rgb(0.0, 0.0, 0.0);
+        for (index, _) in self.board.cells.iter().enumerate().filter(|(_, alive)| **alive) {
+            let x = (index % WIDTH) as i32 * CELL_SIZE;
+            let y = (index / WIDTH) as i32 * CELL_SIZE;
+            cx.rectangle(x.into(), y.into(), CELL_SIZE.into(), CELL_SIZE.into());
+        }
+        cx.fill().expect("Couldn't draw the cells");
+    }
+}
+
+#[relm4::component]
+impl SimpleComponent for CanvasApp {
+    type Init = ();
+    type Input = LifeMsg;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            set_title: Some("Game of Life (canvas)"),
+            set_resizable: false,
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+                set_spacing: 5,
+                set_margin_all: 5,
+
+                gtk::Box {
+                    set_spacing: 5,
+
+                    gtk::Button {
+                        #[watch]
+                        set_label: if model.running { "Pause" } else { "Play" },
+                        connect_clicked => LifeMsg::TogglePause,
+                    },
+                    gtk::Button {
+                        set_label: "Randomize",
+                        connect_clicked => LifeMsg::Randomize,
+                    },
+                    gtk::Label {
+                        #[watch]
+                        set_label: &format!("{:.0} generations/s", model.generations.per_second),
+                    },
+                },
+
+                #[local_ref]
+                area -> gtk::DrawingArea {
+                    set_size_request: (WIDTH as i32 * CELL_SIZE, HEIGHT as i32 * CELL_SIZE),
+                },
+            },
+        }
+    }
+
+    fn init(_: (), root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+        let model = CanvasApp {
+            board: Board::random(),
+            handler: DrawHandler::new(),
+            running: true,
+            generations: GenerationCounter::new(),
+        };
+
+        let area = model.handler.drawing_area();
+        let widgets = view_output!();
+
+        start_ticker(sender.input_sender().clone());
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            LifeMsg::Tick => {
+                if !self.running {
+                    return;
+                }
+                self.board.step();
+                self.draw();
+                self.generations.generation();
+            }
+            LifeMsg::TogglePause => self.running = !self.running,
+            LifeMsg::Randomize => {
+                self.board = Board::random();
+                self.draw();
+            }
+        }
+    }
+}
+// ANCHOR_END: canvas_app
+
+// ANCHOR: main
+fn main() {
+    // The implementation is chosen by the first argument, which GTK shouldn't see
+    let canvas = std::env::args().nth(1).as_deref() == Some("canvas");
+    let app = RelmApp::new("relm4.example.game_of_life").with_args(Vec::new());
+
+    if canvas {
+        app.run::<CanvasApp>(());
+    } else {
+        app.run::<FactoryApp>(());
+    }
+}
+// ANCHOR_END: main
+// ANCHOR_END: all
@@ -345,6 +345,7 @@ impl SimpleComponent for AppModel {
         })();
     }
     // ANCHOR_END: update_view
+    // ANCHOR: init
     // ANCHOR: pre_init
     fn init(
         init: Self::Init,
@@ -0,0 +1,268 @@
+use relm4::adw::prelude::*;
+use relm4::prelude::*;
+use relm4::{adw, Controller};
+
+/// Configuration for the alert dialog component
+pub struct AlertSettings {
+    /// Large text
+    pub text: String,
+    /// Optional secondary, smaller text
+    pub secondary_text: Option<String>,
+    /// Sets color of the accept button to red if the theme supports it
+    pub destructive_accept: bool,
+    /// Text for confirm button
+    pub confirm_label: String,
+    /// Text for cancel button
+    pub cancel_label: String,
+    /// Text for third option button. If [`None`] the third button won't be created.
+    pub option_label: Option<String>,
+}
+
+// Response ids used by the dialog.
+const CONFIRM: &str = "confirm";
+const CANCEL: &str = "cancel";
+const OPTION: &str = "option";
+
+/// Alert dialog component.
+pub struct Alert {
+    settings: AlertSettings,
+}
+
+/// Messages that can be sent to the alert dialog component
+#[derive(Debug)]
+pub enum AlertMsg {
+    /// Message sent by the parent to view the dialog
+    Show,
+
+    #[doc(hidden)]
+    Response(String),
+}
+
+/// User action performed on the alert dialog.
+#[derive(Debug)]
+pub enum AlertResponse {
+    /// User clicked confirm button.
+    Confirm,
+
+    /// User clicked cancel button.
+    Cancel,
+
+    /// User clicked user-supplied option.
+    Option,
+}
+
+/// Widgets of the alert dialog component.
+#[relm4::component(pub)]
+impl Component for Alert {
+    type Widgets = AlertWidgets;
+    type Init = AlertSettings;
+    type Input = AlertMsg;
+    type Output = AlertResponse;
+    type CommandOutput = ();
+
+    // Adwaita dialogs are always modal and close themselves
+    // after a response, so there's no `is_modal` setting and
+    // no need to track whether the dialog is visible.
+    view! {
+        #[name = "dialog"]
+        adw::AlertDialog {
+            // Apply configuration
+            set_heading: Some(&model.settings.text),
+            set_body: model.settings.secondary_text.as_deref().unwrap_or_default(),
+            add_response: (CANCEL, &model.settings.cancel_label),
+            add_response: (CONFIRM, &model.settings.confirm_label),
+            set_default_response: Some(CANCEL),
+            set_close_response: CANCEL,
+        }
+    }
+
+    fn init(
+        settings: AlertSettings,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Alert { settings };
+
+        let widgets = view_output!();
+
+        if let Some(option_label) = &model.settings.option_label {
+            widgets.dialog.add_response(OPTION, option_label);
+        }
+
+        if model.settings.destructive_accept {
+            widgets
+                .dialog
+                .set_response_appearance(CONFIRM, adw::ResponseAppearance::Destructive);
+        }
+
+        widgets.dialog.connect_response(None, move |_, response| {
+            sender.input(AlertMsg::Response(response.to_owned()));
+        });
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, input: AlertMsg, sender: ComponentSender<Self>, root: &Self::Root) {
+        match input {
+            AlertMsg::Show => {
+                root.present(relm4::main_application().active_window().as_ref());
+            }
+            AlertMsg::Response(response) => {
+                sender
+                    .output(match response.as_str() {
+                        CONFIRM => AlertResponse::Confirm,
+                        OPTION => AlertResponse::Option,
+                        _ => AlertResponse::Cancel,
+                    })
+                    .unwrap();
+            }
+        }
+    }
+}
+
+struct App {
+    counter: u8,
+    alert_toggle: bool,
+    dialog: Controller<Alert>,
+    second_dialog: Controller<Alert>,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    Increment,
+    Decrement,
+    CloseRequest,
+    Save,
+    Close,
+    Ignore,
+}
+
+#[relm4::component]
+impl SimpleComponent for App {
+    type Input = AppMsg;
+    type Output = ();
+    type Init = ();
+
+    view! {
+        main_window = adw::ApplicationWindow {
+            set_title: Some("Simple app"),
+            set_default_width: 300,
+            set_default_height: 100,
+
+            connect_close_request[sender] => move |_| {
+                sender.input(AppMsg::CloseRequest);
+                gtk::glib::Propagation::Stop
+            },
+
+            adw::ToolbarView {
+                add_top_bar = &adw::HeaderBar {},
+
+                #[wrap(Some)]
+                set_content = &gtk::Box {
+                    set_orientation: gtk::Orientation::Vertical,
+                    set_margin_all: 5,
+                    set_spacing: 5,
+
+                    append = &gtk::Button {
+                        set_label: "Increment",
+                        connect_clicked[sender] => move |_| {
+                            sender.input(AppMsg::Increment);
+                        },
+                    },
+                    append = &gtk::Button {
+                        set_label: "Decrement",
+                        connect_clicked[sender] => move |_| {
+                            sender.input(AppMsg::Decrement);
+                        },
+                    },
+                    append = &gtk::Label {
+                        set_margin_all: 5,
+                        #[watch]
+                        set_label: &format!("Counter: {}", model.counter),
+                    },
+                    append = &gtk::Button {
+                        set_label: "Close",
+                        add_css_class: "destructive-action",
+                        connect_clicked[sender] => move |_| {
+                            sender.input(AppMsg::CloseRequest);
+                        },
+                    },
+                },
+            },
+        }
+    }
+
+    fn update(&mut self, msg: AppMsg, _sender: ComponentSender<Self>) {
+        match msg {
+            AppMsg::Increment => {
+                self.counter = self.counter.wrapping_add(1);
+            }
+            AppMsg::Decrement => {
+                self.counter = self.counter.wrapping_sub(1);
+            }
+            AppMsg::CloseRequest => {
+                if self.counter == 42 {
+                    relm4::main_application().quit();
+                } else {
+                    self.alert_toggle = !self.alert_toggle;
+                    if self.alert_toggle {
+                        self.dialog.emit(AlertMsg::Show);
+                    } else {
+                        self.second_dialog.emit(AlertMsg::Show);
+                    }
+                }
+            }
+            AppMsg::Save => {
+                println!("* Open save dialog here *");
+            }
+            AppMsg::Close => {
+                relm4::main_application().quit();
+            }
+            AppMsg::Ignore => (),
+        }
+    }
+
+    fn init(_: (), root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+        let model = App {
+            counter: 0,
+            alert_toggle: false,
+            dialog: Alert::builder()
+                .launch(AlertSettings {
+                    text: String::from("Do you want to quit without saving? (First alert)"),
+                    secondary_text: Some(String::from("Your counter hasn't reached 42 yet")),
+                    confirm_label: String::from("Close without saving"),
+                    cancel_label: String::from("Cancel"),
+                    option_label: Some(String::from("Save")),
+                    destructive_accept: true,
+                })
+                .forward(sender.input_sender(), convert_alert_response),
+            second_dialog: Alert::builder()
+                .launch(AlertSettings {
+                    text: String::from("Do you want to quit without saving? (Second alert)"),
+                    secondary_text: Some(String::from("Your counter hasn't reached 42 yet")),
+                    confirm_label: String::from("Close without saving"),
+                    cancel_label: String::from("Cancel"),
+                    option_label: Some(String::from("Save")),
+                    destructive_accept: true,
+                })
+                .forward(sender.input_sender(), convert_alert_response),
+        };
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+}
+
+fn convert_alert_response(response: AlertResponse) -> AppMsg {
+    match response {
+        AlertResponse::Confirm => AppMsg::Close,
+        AlertResponse::Cancel => AppMsg::Ignore,
+        AlertResponse::Option => AppMsg::Save,
+    }
+}
+
+fn main() {
+    let app = RelmApp::new("relm4.adw_test.alert");
+    app.run::<App>(());
+}
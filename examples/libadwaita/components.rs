@@ -0,0 +1,228 @@
+use relm4::adw::prelude::*;
+use relm4::*;
+
+struct HeaderModel;
+
+#[derive(Debug)]
+enum HeaderOutput {
+    View,
+    Edit,
+    Export,
+}
+
+#[relm4::component]
+impl SimpleComponent for HeaderModel {
+    type Init = ();
+    type Input = ();
+    type Output = HeaderOutput;
+
+    view! {
+        #[root]
+        adw::HeaderBar {
+            #[wrap(Some)]
+            set_title_widget = &gtk::Box {
+                add_css_class: "linked",
+                #[name = "group"]
+                gtk::ToggleButton {
+                    set_label: "View",
+                    set_active: true,
+                    connect_toggled[sender] => move |btn| {
+                        if btn.is_active() {
+                            sender.output(HeaderOutput::View).unwrap()
+                        }
+                    },
+                },
+                gtk::ToggleButton {
+                    set_label: "Edit",
+                    set_group: Some(&group),
+                    connect_toggled[sender] => move |btn| {
+                        if btn.is_active() {
+                            sender.output(HeaderOutput::Edit).unwrap()
+                        }
+                    },
+                },
+                gtk::ToggleButton {
+                    set_label: "Export",
+                    set_group: Some(&group),
+                    connect_toggled[sender] => move |btn| {
+                        if btn.is_active() {
+                            sender.output(HeaderOutput::Export).unwrap()
+                        }
+                    },
+                },
+            }
+        }
+    }
+
+    fn init(
+        _params: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = HeaderModel;
+        let widgets = view_output!();
+        ComponentParts { model, widgets }
+    }
+}
+
+struct DialogModel;
+
+#[derive(Debug)]
+enum DialogInput {
+    Show,
+    Response(String),
+}
+
+#[derive(Debug)]
+enum DialogOutput {
+    Close,
+}
+
+// `adw::AlertDialog` isn't a window and closes itself after a response,
+// so instead of watching a `hidden` field, the dialog is presented
+// on demand. This requires access to the root in `update`.
+#[relm4::component]
+impl Component for DialogModel {
+    type Init = ();
+    type Input = DialogInput;
+    type Output = DialogOutput;
+    type CommandOutput = ();
+
+    view! {
+        adw::AlertDialog {
+            set_heading: Some("Do you want to close before saving?"),
+            set_body: "All unsaved changes will be lost",
+            add_response: ("cancel", "Cancel"),
+            add_response: ("close", "Close"),
+            set_response_appearance: ("close", adw::ResponseAppearance::Destructive),
+            set_default_response: Some("cancel"),
+            set_close_response: "cancel",
+        }
+    }
+
+    fn init(
+        _params: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = DialogModel;
+        let widgets = view_output!();
+
+        root.connect_response(None, move |_, response| {
+            sender.input(DialogInput::Response(response.to_owned()));
+        });
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, sender: ComponentSender<Self>, root: &Self::Root) {
+        match msg {
+            DialogInput::Show => {
+                root.present(relm4::main_application().active_window().as_ref());
+            }
+            DialogInput::Response(response) => {
+                if response == "close" {
+                    sender.output(DialogOutput::Close).unwrap()
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+enum AppMode {
+    View,
+    Edit,
+    Export,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    SetMode(AppMode),
+    CloseRequest,
+    Close,
+}
+
+struct AppModel {
+    mode: AppMode,
+    header: Controller<HeaderModel>,
+    dialog: Controller<DialogModel>,
+}
+
+#[relm4::component]
+impl SimpleComponent for AppModel {
+    type Init = AppMode;
+    type Input = AppMsg;
+    type Output = ();
+
+    view! {
+        main_window = adw::ApplicationWindow {
+            set_default_width: 500,
+            set_default_height: 250,
+
+            adw::ToolbarView {
+                add_top_bar: model.header.widget(),
+
+                #[wrap(Some)]
+                set_content = &gtk::Label {
+                    #[watch]
+                    set_label: &format!("Placeholder for {:?}", model.mode),
+                },
+            },
+
+            connect_close_request[sender] => move |_| {
+                sender.input(AppMsg::CloseRequest);
+                gtk::glib::Propagation::Stop
+            }
+        }
+    }
+
+    fn init(
+        params: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let header: Controller<HeaderModel> =
+            HeaderModel::builder()
+                .launch(())
+                .forward(sender.input_sender(), |msg| match msg {
+                    HeaderOutput::View => AppMsg::SetMode(AppMode::View),
+                    HeaderOutput::Edit => AppMsg::SetMode(AppMode::Edit),
+                    HeaderOutput::Export => AppMsg::SetMode(AppMode::Export),
+                });
+
+        let dialog = DialogModel::builder()
+            .launch(())
+            .forward(sender.input_sender(), |msg| match msg {
+                DialogOutput::Close => AppMsg::Close,
+            });
+
+        let model = AppModel {
+            mode: params,
+            header,
+            dialog,
+        };
+
+        let widgets = view_output!();
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            AppMsg::SetMode(mode) => {
+                self.mode = mode;
+            }
+            AppMsg::CloseRequest => {
+                self.dialog.emit(DialogInput::Show);
+            }
+            AppMsg::Close => {
+                relm4::main_application().quit();
+            }
+        }
+    }
+}
+
+fn main() {
+    let relm = RelmApp::new("relm4.adw_test.components");
+    relm.run::<AppModel>(AppMode::Edit);
+}
@@ -0,0 +1,217 @@
+use relm4::adw::prelude::*;
+use relm4::factory::{DynamicIndex, FactoryComponent, FactorySender, FactoryVecDeque};
+use relm4::{adw, gtk, ComponentParts, ComponentSender, RelmApp, RelmWidgetExt, SimpleComponent};
+
+#[derive(Debug)]
+struct Counter {
+    value: u8,
+}
+
+#[derive(Debug)]
+enum CounterMsg {
+    Increment,
+    Decrement,
+}
+
+#[derive(Debug)]
+enum CounterOutput {
+    SendFront(DynamicIndex),
+    MoveUp(DynamicIndex),
+    MoveDown(DynamicIndex),
+}
+
+#[relm4::factory]
+impl FactoryComponent for Counter {
+    type Init = u8;
+    type Input = CounterMsg;
+    type Output = CounterOutput;
+    type CommandOutput = ();
+    type ParentWidget = gtk::ListBox;
+
+    view! {
+        // `adw::ActionRow` already is a `gtk::ListBoxRow`,
+        // so the list box doesn't wrap it in another row.
+        #[root]
+        adw::ActionRow {
+            #[watch]
+            set_title: &self.value.to_string(),
+
+            add_suffix = &gtk::Button {
+                set_icon_name: "list-add-symbolic",
+                set_tooltip_text: Some("Increment"),
+                set_valign: gtk::Align::Center,
+                add_css_class: "flat",
+                connect_clicked => CounterMsg::Increment,
+            },
+
+            add_suffix = &gtk::Button {
+                set_icon_name: "list-remove-symbolic",
+                set_tooltip_text: Some("Decrement"),
+                set_valign: gtk::Align::Center,
+                add_css_class: "flat",
+                connect_clicked => CounterMsg::Decrement,
+            },
+
+            add_suffix = &gtk::Button {
+                set_icon_name: "go-up-symbolic",
+                set_tooltip_text: Some("Move up"),
+                set_valign: gtk::Align::Center,
+                add_css_class: "flat",
+                connect_clicked[sender, index] => move |_| {
+                    sender.output(CounterOutput::MoveUp(index.clone())).unwrap();
+                }
+            },
+
+            add_suffix = &gtk::Button {
+                set_icon_name: "go-down-symbolic",
+                set_tooltip_text: Some("Move down"),
+                set_valign: gtk::Align::Center,
+                add_css_class: "flat",
+                connect_clicked[sender, index] => move |_| {
+                    sender.output(CounterOutput::MoveDown(index.clone())).unwrap();
+                }
+            },
+
+            add_suffix = &gtk::Button {
+                set_icon_name: "go-top-symbolic",
+                set_tooltip_text: Some("To start"),
+                set_valign: gtk::Align::Center,
+                add_css_class: "flat",
+                connect_clicked[sender, index] => move |_| {
+                    sender.output(CounterOutput::SendFront(index.clone())).unwrap();
+                }
+            },
+        }
+    }
+
+    fn init_model(value: Self::Init, _index: &DynamicIndex, _sender: FactorySender<Self>) -> Self {
+        Self { value }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: FactorySender<Self>) {
+        match msg {
+            CounterMsg::Increment => {
+                self.value = self.value.wrapping_add(1);
+            }
+            CounterMsg::Decrement => {
+                self.value = self.value.wrapping_sub(1);
+            }
+        }
+    }
+}
+
+struct App {
+    created_widgets: u8,
+    counters: FactoryVecDeque<Counter>,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    AddCounter,
+    RemoveCounter,
+    SendFront(DynamicIndex),
+    MoveUp(DynamicIndex),
+    MoveDown(DynamicIndex),
+}
+
+#[relm4::component]
+impl SimpleComponent for App {
+    type Init = u8;
+    type Input = AppMsg;
+    type Output = ();
+
+    view! {
+        adw::ApplicationWindow {
+            set_title: Some("Factory example"),
+            set_default_size: (400, 400),
+
+            adw::ToolbarView {
+                add_top_bar = &adw::HeaderBar {
+                    pack_start = &gtk::Button {
+                        set_icon_name: "list-add-symbolic",
+                        set_tooltip_text: Some("Add counter"),
+                        connect_clicked => AppMsg::AddCounter,
+                    },
+
+                    pack_start = &gtk::Button {
+                        set_icon_name: "list-remove-symbolic",
+                        set_tooltip_text: Some("Remove counter"),
+                        connect_clicked => AppMsg::RemoveCounter,
+                    },
+                },
+
+                #[wrap(Some)]
+                set_content = &gtk::ScrolledWindow {
+                    adw::Clamp {
+                        #[local_ref]
+                        counter_box -> gtk::ListBox {
+                            set_margin_all: 12,
+                            set_valign: gtk::Align::Start,
+                            set_selection_mode: gtk::SelectionMode::None,
+                            add_css_class: "boxed-list",
+                        }
+                    }
+                },
+            }
+        }
+    }
+
+    fn init(
+        counter: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let counters = FactoryVecDeque::builder()
+            .launch(gtk::ListBox::default())
+            .forward(sender.input_sender(), |output| match output {
+                CounterOutput::SendFront(index) => AppMsg::SendFront(index),
+                CounterOutput::MoveUp(index) => AppMsg::MoveUp(index),
+                CounterOutput::MoveDown(index) => AppMsg::MoveDown(index),
+            });
+
+        let model = App {
+            created_widgets: counter,
+            counters,
+        };
+
+        let counter_box = model.counters.widget();
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            AppMsg::AddCounter => {
+                self.counters.guard().push_back(self.created_widgets);
+                self.created_widgets = self.created_widgets.wrapping_add(1);
+            }
+            AppMsg::RemoveCounter => {
+                self.counters.guard().pop_back();
+            }
+            AppMsg::SendFront(index) => {
+                self.counters.guard().move_front(index.current_index());
+            }
+            AppMsg::MoveDown(index) => {
+                let index = index.current_index();
+                let new_index = index + 1;
+                // Already at the end?
+                if new_index < self.counters.len() {
+                    self.counters.guard().move_to(index, new_index);
+                }
+            }
+            AppMsg::MoveUp(index) => {
+                let index = index.current_index();
+                // Already at the start?
+                if index != 0 {
+                    self.counters.guard().move_to(index, index - 1);
+                }
+            }
+        }
+    }
+}
+
+fn main() {
+    let app = RelmApp::new("relm4.adw_test.factory");
+    app.run::<App>(0);
+}
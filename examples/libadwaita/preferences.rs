@@ -0,0 +1,436 @@
+/* ANCHOR: all */
+use std::path::PathBuf;
+
+use gtk::glib::clone;
+use relm4::adw::prelude::*;
+use relm4::{
+    adw, gtk, Component, ComponentController, ComponentParts, ComponentSender, Controller,
+    RelmApp, RelmWidgetExt, SimpleComponent,
+};
+
+// ANCHOR: preferences
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Theme {
+    System,
+    Light,
+    Dark,
+}
+
+impl Theme {
+    const ALL: [Self; 3] = [Self::System, Self::Light, Self::Dark];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::System => "System",
+            Self::Light => "Light",
+            Self::Dark => "Dark",
+        }
+    }
+
+    fn color_scheme(self) -> adw::ColorScheme {
+        match self {
+            Self::System => adw::ColorScheme::Default,
+            Self::Light => adw::ColorScheme::ForceLight,
+            Self::Dark => adw::ColorScheme::ForceDark,
+        }
+    }
+}
+
+/// Settings of the app that are saved between sessions.
+#[derive(Debug, Clone, PartialEq)]
+struct Preferences {
+    spacing: u32,
+    theme: Theme,
+    flat_buttons: bool,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Self {
+            spacing: 5,
+            theme: Theme::System,
+            flat_buttons: false,
+        }
+    }
+}
+
+impl Preferences {
+    fn path() -> PathBuf {
+        gtk::glib::user_config_dir()
+            .join("relm4-adw-preferences")
+            .join("preferences")
+    }
+
+    /// Reads the settings file, missing or invalid entries keep their default value.
+    fn load() -> Self {
+        let mut preferences = Self::default();
+        let Ok(content) = std::fs::read_to_string(Self::path()) else {
+            return preferences;
+        };
+
+        for (key, value) in content.lines().filter_map(|line| line.split_once('=')) {
+            match key.trim() {
+                "spacing" => {
+                    if let Ok(spacing) = value.trim().parse() {
+                        preferences.spacing = spacing;
+                    }
+                }
+                "theme" => {
+                    if let Some(theme) = Theme::ALL
+                        .into_iter()
+                        .find(|theme| theme.label() == value.trim())
+                    {
+                        preferences.theme = theme;
+                    }
+                }
+                "flat_buttons" => {
+                    if let Ok(flat_buttons) = value.trim().parse() {
+                        preferences.flat_buttons = flat_buttons;
+                    }
+                }
+                _ => (),
+            }
+        }
+        preferences
+    }
+
+    fn save(&self) {
+        let path = Self::path();
+        let content = format!(
+            "spacing={}\ntheme={}\nflat_buttons={}\n",
+            self.spacing,
+            self.theme.label(),
+            self.flat_buttons
+        );
+        let result = std::fs::create_dir_all(path.parent().unwrap())
+            .and_then(|()| std::fs::write(&path, content));
+
+        if let Err(err) = result {
+            eprintln!("Could not save preferences: {err}");
+        }
+    }
+}
+// ANCHOR_END: preferences
+
+// ANCHOR: dialog_model
+struct PreferencesModel {
+    preferences: Preferences,
+}
+
+#[derive(Debug)]
+enum PreferencesMsg {
+    SetSpacing(u32),
+    SetTheme(Theme),
+    SetFlatButtons(bool),
+    Reset,
+}
+
+#[derive(Debug)]
+enum PreferencesOutput {
+    Changed(Preferences),
+}
+
+struct PreferencesWidgets {
+    spacing_row: adw::SpinRow,
+    theme_row: adw::ComboRow,
+    flat_buttons_row: adw::SwitchRow,
+}
+// ANCHOR_END: dialog_model
+
+impl SimpleComponent for PreferencesModel {
+    type Input = PreferencesMsg;
+    type Output = PreferencesOutput;
+
+    type Init = Preferences;
+
+    type Root = adw::PreferencesDialog;
+    type Widgets = PreferencesWidgets;
+
+    fn init_root() -> Self::Root {
+        adw::PreferencesDialog::new()
+    }
+
+    // ANCHOR: dialog_init
+    fn init(
+        preferences: Self::Init,
+        dialog: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = PreferencesModel { preferences };
+
+        let page = adw::PreferencesPage::new();
+        let group = adw::PreferencesGroup::builder()
+            .title("Appearance")
+            .build();
+
+        let reset_button = gtk::Button::builder()
+            .label("Reset")
+            .valign(gtk::Align::Center)
+            .build();
+        reset_button.add_css_class("flat");
+        group.set_header_suffix(Some(&reset_button));
+
+        let spacing_row = adw::SpinRow::with_range(0.0, 24.0, 1.0);
+        spacing_row.set_title("Spacing");
+        spacing_row.set_value(model.preferences.spacing.into());
+
+        let themes: Vec<&str> = Theme::ALL.iter().map(|theme| theme.label()).collect();
+        let theme_row = adw::ComboRow::builder()
+            .title("Theme")
+            .model(&gtk::StringList::new(&themes))
+            .build();
+        theme_row.set_selected(model.preferences.theme as u32);
+
+        let flat_buttons_row = adw::SwitchRow::builder()
+            .title("Flat buttons")
+            .active(model.preferences.flat_buttons)
+            .build();
+
+        dialog.add(&page);
+        page.add(&group);
+        group.add(&spacing_row);
+        group.add(&theme_row);
+        group.add(&flat_buttons_row);
+
+        // Widgets to model. Setting a row to the value it already has doesn't emit
+        // a notification, so updating the rows in `update_view` doesn't cause a loop.
+        spacing_row.connect_value_notify(clone!(
+            #[strong]
+            sender,
+            move |row| {
+                sender.input(PreferencesMsg::SetSpacing(row.value() as u32));
+            }
+        ));
+
+        theme_row.connect_selected_notify(clone!(
+            #[strong]
+            sender,
+            move |row| {
+                if let Some(theme) = Theme::ALL.get(row.selected() as usize) {
+                    sender.input(PreferencesMsg::SetTheme(*theme));
+                }
+            }
+        ));
+
+        flat_buttons_row.connect_active_notify(clone!(
+            #[strong]
+            sender,
+            move |row| {
+                sender.input(PreferencesMsg::SetFlatButtons(row.is_active()));
+            }
+        ));
+
+        reset_button.connect_clicked(clone!(
+            #[strong]
+            sender,
+            move |_| {
+                sender.input(PreferencesMsg::Reset);
+            }
+        ));
+
+        let widgets = PreferencesWidgets {
+            spacing_row,
+            theme_row,
+            flat_buttons_row,
+        };
+
+        ComponentParts { model, widgets }
+    }
+    // ANCHOR_END: dialog_init
+
+    // ANCHOR: dialog_update
+    fn update(&mut self, message: Self::Input, sender: ComponentSender<Self>) {
+        let mut preferences = self.preferences.clone();
+        match message {
+            PreferencesMsg::SetSpacing(spacing) => preferences.spacing = spacing,
+            PreferencesMsg::SetTheme(theme) => preferences.theme = theme,
+            PreferencesMsg::SetFlatButtons(flat_buttons) => {
+                preferences.flat_buttons = flat_buttons;
+            }
+            PreferencesMsg::Reset => preferences = Preferences::default(),
+        }
+
+        if preferences != self.preferences {
+            self.preferences = preferences;
+            sender
+                .output(PreferencesOutput::Changed(self.preferences.clone()))
+                .unwrap();
+        }
+    }
+
+    /// Model to widgets, e.g. after a reset.
+    fn update_view(&self, widgets: &mut Self::Widgets, _sender: ComponentSender<Self>) {
+        widgets
+            .spacing_row
+            .set_value(self.preferences.spacing.into());
+        widgets
+            .theme_row
+            .set_selected(self.preferences.theme as u32);
+        widgets
+            .flat_buttons_row
+            .set_active(self.preferences.flat_buttons);
+    }
+    // ANCHOR_END: dialog_update
+}
+
+// ANCHOR: app_model
+struct AppModel {
+    counter: u8,
+    preferences: Preferences,
+    dialog: Controller<PreferencesModel>,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    Increment,
+    Decrement,
+    PreferencesChanged(Preferences),
+}
+
+struct AppWidgets {
+    content: gtk::Box,
+    buttons: [gtk::Button; 2],
+    label: gtk::Label,
+}
+// ANCHOR_END: app_model
+
+impl SimpleComponent for AppModel {
+    type Input = AppMsg;
+    type Output = ();
+
+    type Init = u8;
+
+    type Root = adw::Window;
+    type Widgets = AppWidgets;
+
+    fn init_root() -> Self::Root {
+        adw::Window::builder().title("Preferences").build()
+    }
+
+    // ANCHOR: app_init
+    fn init(
+        counter: Self::Init,
+        window: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let preferences = Preferences::load();
+        adw::StyleManager::default().set_color_scheme(preferences.theme.color_scheme());
+
+        let dialog = PreferencesModel::builder()
+            .launch(preferences.clone())
+            .forward(sender.input_sender(), |output| match output {
+                PreferencesOutput::Changed(preferences) => AppMsg::PreferencesChanged(preferences),
+            });
+
+        let model = AppModel {
+            counter,
+            preferences,
+            dialog,
+        };
+
+        let vbox = gtk::Box::builder()
+            .orientation(gtk::Orientation::Vertical)
+            .build();
+
+        let content = gtk::Box::builder()
+            .orientation(gtk::Orientation::Vertical)
+            .build();
+
+        let preferences_button = gtk::Button::from_icon_name("preferences-system-symbolic");
+        preferences_button.set_tooltip_text(Some("Preferences"));
+
+        let header = adw::HeaderBar::builder()
+            .title_widget(&gtk::Label::new(Some("Preferences")))
+            .build();
+        header.pack_end(&preferences_button);
+
+        let inc_button = gtk::Button::with_label("Increment");
+        let dec_button = gtk::Button::with_label("Decrement");
+
+        let label = gtk::Label::new(None);
+        label.set_margin_all(5);
+
+        window.set_content(Some(&vbox));
+        content.set_margin_all(5);
+        content.append(&inc_button);
+        content.append(&dec_button);
+        content.append(&label);
+        vbox.append(&header);
+        vbox.append(&content);
+
+        // Opening the dialog only concerns the widgets, so no message is needed
+        let dialog_root = model.dialog.widget().clone();
+        preferences_button.connect_clicked(clone!(
+            #[weak]
+            window,
+            move |_| {
+                dialog_root.present(Some(&window));
+            }
+        ));
+
+        inc_button.connect_clicked(clone!(
+            #[strong]
+            sender,
+            move |_| {
+                sender.input(AppMsg::Increment);
+            }
+        ));
+
+        dec_button.connect_clicked(clone!(
+            #[strong]
+            sender,
+            move |_| {
+                sender.input(AppMsg::Decrement);
+            }
+        ));
+
+        let mut widgets = AppWidgets {
+            content,
+            buttons: [inc_button, dec_button],
+            label,
+        };
+        model.update_view(&mut widgets, sender);
+
+        ComponentParts { model, widgets }
+    }
+    // ANCHOR_END: app_init
+
+    // ANCHOR: app_update
+    fn update(&mut self, message: Self::Input, _sender: ComponentSender<Self>) {
+        match message {
+            AppMsg::Increment => {
+                self.counter = self.counter.wrapping_add(1);
+            }
+            AppMsg::Decrement => {
+                self.counter = self.counter.wrapping_sub(1);
+            }
+            AppMsg::PreferencesChanged(preferences) => {
+                preferences.save();
+                adw::StyleManager::default().set_color_scheme(preferences.theme.color_scheme());
+                self.preferences = preferences;
+            }
+        }
+    }
+
+    /// Update the view to represent the updated model.
+    fn update_view(&self, widgets: &mut Self::Widgets, _sender: ComponentSender<Self>) {
+        widgets
+            .label
+            .set_label(&format!("Counter: {}", self.counter));
+        widgets
+            .content
+            .set_spacing(self.preferences.spacing as i32);
+        for button in &widgets.buttons {
+            button.set_class_active("flat", self.preferences.flat_buttons);
+        }
+    }
+    // ANCHOR_END: app_update
+}
+
+// ANCHOR: main
+fn main() {
+    let app = RelmApp::new("relm4.adw_test.preferences");
+    app.run::<AppModel>(0);
+}
+// ANCHOR_END: main
+/* ANCHOR_END: all */
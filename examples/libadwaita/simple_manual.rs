@@ -111,7 +111,7 @@ impl SimpleComponent for AppModel {
             .set_label(&format!("Counter: {}", self.counter));
     }
 }
-// ANCHOR_END: app_update
+// ANCHOR_END: simple_component
 
 // ANCHOR: main
 fn main() {
@@ -0,0 +1,151 @@
+/* ANCHOR: all */
+use relm4::adw::prelude::*;
+use relm4::{adw, gtk, ComponentParts, ComponentSender, RelmApp, SimpleComponent};
+
+// ANCHOR: folders
+/// Name, icon and description of each sidebar entry.
+const FOLDERS: &[(&str, &str, &str)] = &[
+    ("Inbox", "mail-inbox-symbolic", "New messages show up here"),
+    ("Sent", "mail-send-symbolic", "Messages you have sent"),
+    ("Drafts", "document-edit-symbolic", "Messages you haven't sent yet"),
+    ("Archive", "folder-symbolic", "Messages you want to keep"),
+    ("Trash", "user-trash-symbolic", "Deleted messages"),
+];
+// ANCHOR_END: folders
+
+// ANCHOR: model
+struct AppModel {
+    /// Index of the selected folder, kept when the layout changes.
+    selected: usize,
+    /// Whether the content page is visible while the layout is collapsed.
+    show_content: bool,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    Select(usize),
+    ShowContent(bool),
+}
+// ANCHOR_END: model
+
+#[relm4::component]
+impl SimpleComponent for AppModel {
+    type Init = ();
+    type Input = AppMsg;
+    type Output = ();
+
+    // ANCHOR: view
+    view! {
+        adw::ApplicationWindow {
+            set_title: Some("Adaptive layout"),
+            set_default_size: (800, 500),
+            set_size_request: (360, 294),
+
+            #[name = "split_view"]
+            adw::NavigationSplitView {
+                #[wrap(Some)]
+                set_sidebar = &adw::NavigationPage {
+                    set_title: "Folders",
+
+                    #[wrap(Some)]
+                    set_child = &adw::ToolbarView {
+                        add_top_bar = &adw::HeaderBar {},
+
+                        #[wrap(Some)]
+                        set_content = &gtk::ScrolledWindow {
+                            #[name = "sidebar_list"]
+                            gtk::ListBox {
+                                add_css_class: "navigation-sidebar",
+                                // Unlike `row-selected`, this is also emitted if the
+                                // row is selected already, which is needed to open
+                                // the content again in the collapsed layout.
+                                connect_row_activated[sender] => move |_, row| {
+                                    sender.input(AppMsg::Select(row.index() as usize));
+                                },
+                            }
+                        },
+                    },
+                },
+
+                #[wrap(Some)]
+                set_content = &adw::NavigationPage {
+                    #[watch]
+                    set_title: FOLDERS[model.selected].0,
+
+                    #[wrap(Some)]
+                    set_child = &adw::ToolbarView {
+                        add_top_bar = &adw::HeaderBar {},
+
+                        #[wrap(Some)]
+                        set_content = &adw::StatusPage {
+                            #[watch]
+                            set_icon_name: Some(FOLDERS[model.selected].1),
+                            #[watch]
+                            set_title: FOLDERS[model.selected].0,
+                            #[watch]
+                            set_description: Some(FOLDERS[model.selected].2),
+                        },
+                    },
+                },
+
+                #[watch]
+                set_show_content: model.show_content,
+                // The back button and swipe gestures change this without a message.
+                connect_show_content_notify[sender] => move |split_view| {
+                    sender.input(AppMsg::ShowContent(split_view.shows_content()));
+                },
+            },
+        }
+    }
+    // ANCHOR_END: view
+
+    // ANCHOR: init
+    fn init(_: (), root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+        let model = AppModel {
+            selected: 0,
+            show_content: false,
+        };
+
+        let widgets = view_output!();
+
+        for (name, icon, _) in FOLDERS {
+            let row = adw::ActionRow::builder().title(*name).build();
+            row.add_prefix(&gtk::Image::from_icon_name(icon));
+            widgets.sidebar_list.append(&row);
+        }
+        widgets
+            .sidebar_list
+            .select_row(widgets.sidebar_list.row_at_index(model.selected as i32).as_ref());
+
+        // Collapse into a single pane on narrow windows
+        let breakpoint = adw::Breakpoint::new(
+            adw::BreakpointCondition::parse("max-width: 400sp").unwrap(),
+        );
+        breakpoint.add_setter(&widgets.split_view, "collapsed", Some(&true.to_value()));
+        root.add_breakpoint(breakpoint);
+
+        ComponentParts { model, widgets }
+    }
+    // ANCHOR_END: init
+
+    // ANCHOR: update
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            AppMsg::Select(index) => {
+                self.selected = index;
+                // Only has a visible effect in the collapsed layout
+                self.show_content = true;
+            }
+            AppMsg::ShowContent(show_content) => {
+                self.show_content = show_content;
+            }
+        }
+    }
+    // ANCHOR_END: update
+}
+
+fn main() {
+    let app = RelmApp::new("relm4.adw_test.split_view");
+    app.run::<AppModel>(());
+}
+/* ANCHOR_END: all */
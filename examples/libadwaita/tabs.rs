@@ -0,0 +1,339 @@
+/* ANCHOR: all */
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use gtk::glib::{self, clone};
+use relm4::adw::prelude::*;
+use relm4::factory::{DynamicIndex, FactoryComponent, FactorySender, FactoryVecDeque};
+use relm4::{adw, gtk, Component, ComponentParts, ComponentSender, RelmApp, RelmWidgetExt};
+
+static NEXT_ID: AtomicU32 = AtomicU32::new(1);
+
+// ANCHOR: state
+/// Everything that's needed to restore a document, e.g. in another window.
+#[derive(Debug)]
+struct DocumentState {
+    id: u32,
+    text: String,
+    saved_text: String,
+}
+
+impl DocumentState {
+    fn new() -> Self {
+        Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            text: String::new(),
+            saved_text: String::new(),
+        }
+    }
+
+    fn is_modified(&self) -> bool {
+        self.text != self.saved_text
+    }
+}
+// ANCHOR_END: state
+
+// ANCHOR: document
+struct Document {
+    state: DocumentState,
+    /// The page of the tab view that shows this document.
+    /// It's used to update the tab title and to find the document
+    /// when the tab view emits a signal for a page.
+    page: Option<adw::TabPage>,
+}
+
+impl Document {
+    fn update_page(&self) {
+        if let Some(page) = &self.page {
+            page.set_title(&format!("Document {}", self.state.id));
+            page.set_needs_attention(self.state.is_modified());
+        }
+    }
+}
+
+#[derive(Debug)]
+enum DocumentMsg {
+    Edited(String),
+    Save,
+}
+
+#[derive(Debug)]
+enum DocumentOutput {
+    MoveToNewWindow(DynamicIndex),
+}
+
+#[relm4::factory]
+impl FactoryComponent for Document {
+    type Init = DocumentState;
+    type Input = DocumentMsg;
+    type Output = DocumentOutput;
+    type CommandOutput = ();
+    type ParentWidget = adw::TabView;
+
+    view! {
+        #[root]
+        gtk::Box {
+            set_orientation: gtk::Orientation::Vertical,
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Horizontal,
+                set_spacing: 5,
+                set_margin_all: 5,
+
+                gtk::Button {
+                    set_label: "Save",
+                    connect_clicked => DocumentMsg::Save,
+                },
+
+                // Dragging a tab out of the window isn't supported, because the
+                // tab view would move the page, which belongs to this window's factory.
+                // The button moves the state of the document instead.
+                gtk::Button {
+                    set_label: "Move to new window",
+                    connect_clicked[sender, index] => move |_| {
+                        sender.output(DocumentOutput::MoveToNewWindow(index.clone())).unwrap();
+                    },
+                },
+            },
+
+            gtk::ScrolledWindow {
+                set_vexpand: true,
+
+                gtk::TextView {
+                    set_monospace: true,
+                    set_left_margin: 5,
+
+                    #[wrap(Some)]
+                    set_buffer = &gtk::TextBuffer {
+                        set_text: &self.state.text,
+                        connect_changed[sender] => move |buffer| {
+                            let (start, end) = buffer.bounds();
+                            sender.input(DocumentMsg::Edited(buffer.text(&start, &end, false).into()));
+                        },
+                    },
+                },
+            },
+        }
+    }
+
+    fn init_model(state: Self::Init, _index: &DynamicIndex, _sender: FactorySender<Self>) -> Self {
+        Self { state, page: None }
+    }
+
+    fn init_widgets(
+        &mut self,
+        index: &DynamicIndex,
+        root: Self::Root,
+        returned_widget: &adw::TabPage,
+        sender: FactorySender<Self>,
+    ) -> Self::Widgets {
+        let widgets = view_output!();
+
+        self.page = Some(returned_widget.clone());
+        self.update_page();
+
+        widgets
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: FactorySender<Self>) {
+        match msg {
+            DocumentMsg::Edited(text) => self.state.text = text,
+            DocumentMsg::Save => {
+                println!("* Save document {} here *", self.state.id);
+                self.state.saved_text = self.state.text.clone();
+            }
+        }
+        self.update_page();
+    }
+}
+// ANCHOR_END: document
+
+// ANCHOR: window_model
+struct DocumentWindow {
+    documents: FactoryVecDeque<Document>,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    NewTab,
+    MoveToNewWindow(DynamicIndex),
+    ClosePage(adw::TabPage),
+    CloseResponse(adw::TabPage, String),
+}
+// ANCHOR_END: window_model
+
+impl DocumentWindow {
+    fn position(&self, page: &adw::TabPage) -> Option<usize> {
+        self.documents
+            .iter()
+            .position(|document| document.page.as_ref() == Some(page))
+    }
+
+    /// Removes a document. The factory closes its page and finishes
+    /// closing it if the tab view was waiting for that.
+    /// The window is closed together with its last page.
+    fn remove(&mut self, index: usize, root: &adw::ApplicationWindow) -> Option<Document> {
+        let document = self.documents.guard().remove(index);
+        if self.documents.is_empty() {
+            root.close();
+        }
+        document
+    }
+}
+
+#[relm4::component]
+impl Component for DocumentWindow {
+    type Init = Vec<DocumentState>;
+    type Input = AppMsg;
+    type Output = ();
+    type CommandOutput = ();
+
+    view! {
+        adw::ApplicationWindow {
+            set_title: Some("Documents"),
+            set_default_size: (700, 500),
+
+            adw::ToolbarView {
+                add_top_bar = &adw::HeaderBar {
+                    pack_start = &gtk::Button {
+                        set_icon_name: "tab-new-symbolic",
+                        set_tooltip_text: Some("New tab"),
+                        connect_clicked => AppMsg::NewTab,
+                    },
+                },
+
+                // Tabs can be reordered by dragging them. This only changes the order
+                // of the pages, so documents are always looked up by their page.
+                add_top_bar = &adw::TabBar {
+                    set_view: Some(tab_view),
+                    set_autohide: false,
+                },
+
+                set_content: Some(tab_view),
+            },
+        }
+    }
+
+    // ANCHOR: window_init
+    fn init(
+        documents: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let mut model = DocumentWindow {
+            documents: FactoryVecDeque::builder()
+                .launch(adw::TabView::default())
+                .forward(sender.input_sender(), |output| match output {
+                    DocumentOutput::MoveToNewWindow(index) => AppMsg::MoveToNewWindow(index),
+                }),
+        };
+
+        let documents = if documents.is_empty() {
+            vec![DocumentState::new()]
+        } else {
+            documents
+        };
+        {
+            let mut guard = model.documents.guard();
+            for document in documents {
+                guard.push_back(document);
+            }
+        }
+
+        let tab_view = model.documents.widget();
+        let widgets = view_output!();
+
+        // Closing a page is always deferred, so the model can decide
+        // whether to ask the user first. This is also emitted when the factory
+        // removes a document, but the factory finishes closing the page itself.
+        tab_view.connect_close_page(clone!(
+            #[strong]
+            sender,
+            move |_, page| {
+                sender.input(AppMsg::ClosePage(page.clone()));
+                glib::Propagation::Stop
+            }
+        ));
+
+        ComponentParts { model, widgets }
+    }
+    // ANCHOR_END: window_init
+
+    // ANCHOR: window_update
+    fn update(&mut self, msg: Self::Input, sender: ComponentSender<Self>, root: &Self::Root) {
+        match msg {
+            AppMsg::NewTab => {
+                self.documents.guard().push_back(DocumentState::new());
+                if let Some(page) = self
+                    .documents
+                    .iter()
+                    .last()
+                    .and_then(|doc| doc.page.as_ref())
+                {
+                    self.documents.widget().set_selected_page(page);
+                }
+            }
+            AppMsg::MoveToNewWindow(index) => {
+                // The state is moved to a new window with its own factory
+                if let Some(document) = self.remove(index.current_index(), root) {
+                    let mut window = DocumentWindow::builder()
+                        .launch(vec![document.state])
+                        .detach();
+                    window
+                        .widget()
+                        .set_application(Some(&relm4::main_application()));
+                    window.widget().present();
+                    window.detach_runtime();
+                }
+            }
+            AppMsg::ClosePage(page) => {
+                // Pages that were removed by the factory are already gone
+                let Some(index) = self.position(&page) else {
+                    return;
+                };
+
+                if !self.documents[index].state.is_modified() {
+                    self.remove(index, root);
+                    return;
+                }
+
+                let dialog = adw::AlertDialog::new(
+                    Some("Close document?"),
+                    Some("The document has unsaved changes that will be lost"),
+                );
+                dialog.add_response("cancel", "Cancel");
+                dialog.add_response("discard", "Discard");
+                dialog.set_response_appearance("discard", adw::ResponseAppearance::Destructive);
+                dialog.set_close_response("cancel");
+                dialog.connect_response(
+                    None,
+                    clone!(
+                        #[strong]
+                        sender,
+                        move |_, response| {
+                            sender.input(AppMsg::CloseResponse(page.clone(), response.to_owned()));
+                        }
+                    ),
+                );
+                dialog.present(Some(root));
+            }
+            AppMsg::CloseResponse(page, response) => {
+                let Some(index) = self.position(&page) else {
+                    return;
+                };
+
+                if response == "discard" {
+                    self.remove(index, root);
+                } else {
+                    self.documents.widget().close_page_finish(&page, false);
+                }
+            }
+        }
+    }
+    // ANCHOR_END: window_update
+}
+
+fn main() {
+    let app = RelmApp::new("relm4.adw_test.tabs");
+    app.run::<DocumentWindow>(Vec::new());
+}
+/* ANCHOR_END: all */
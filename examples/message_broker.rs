@@ -0,0 +1,313 @@
+// ANCHOR: all
+use std::collections::VecDeque;
+use std::fmt;
+
+use gtk::prelude::{BoxExt, ButtonExt, GtkWindowExt, OrientableExt, WidgetExt};
+use relm4::factory::{DynamicIndex, FactoryComponent, FactorySender, FactoryVecDeque};
+use relm4::{
+    gtk, Component, ComponentParts, ComponentSender, Controller, MessageBroker, RelmApp,
+    RelmWidgetExt, SimpleComponent,
+};
+
+// ANCHOR: broker
+/// The input sender of the dialog, available to the whole app.
+///
+/// A broker can be used to launch exactly one component.
+/// Launching a second component with it panics, so it must only be used
+/// from code that runs once, such as the `init` function of the main component.
+static DIALOG_BROKER: MessageBroker<DialogMsg> = MessageBroker::new();
+// ANCHOR_END: broker
+
+// ANCHOR: dialog_msg
+/// Called with `true` if the user confirmed.
+///
+/// The broker is a `static`, so its messages must be `Send`.
+struct Reply(Box<dyn FnOnce(bool) + Send>);
+
+impl Reply {
+    fn new(f: impl FnOnce(bool) + Send + 'static) -> Self {
+        Self(Box::new(f))
+    }
+}
+
+// Input messages must implement `Debug`, which closures don't do
+impl fmt::Debug for Reply {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Reply")
+    }
+}
+
+#[derive(Debug)]
+enum Request {
+    Error(String),
+    Confirm { question: String, reply: Reply },
+}
+
+#[derive(Debug)]
+enum DialogMsg {
+    Show(Request),
+    #[doc(hidden)]
+    Respond(bool),
+}
+// ANCHOR_END: dialog_msg
+
+// ANCHOR: dialog
+/// A dialog that can be opened from anywhere in the app.
+struct Dialog {
+    /// Requests that arrive while the dialog is open are shown one after another.
+    /// The first request is the one that's visible.
+    requests: VecDeque<Request>,
+}
+
+impl Dialog {
+    fn text(&self) -> &str {
+        match self.requests.front() {
+            Some(Request::Error(message)) => message,
+            Some(Request::Confirm { question, .. }) => question,
+            None => "",
+        }
+    }
+
+    fn is_confirmation(&self) -> bool {
+        matches!(self.requests.front(), Some(Request::Confirm { .. }))
+    }
+}
+
+#[relm4::component]
+impl SimpleComponent for Dialog {
+    type Init = ();
+    type Input = DialogMsg;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            set_modal: true,
+            set_resizable: false,
+            set_default_width: 300,
+            #[watch]
+            set_title: Some(if model.is_confirmation() { "Confirm" } else { "Error" }),
+            #[watch]
+            set_visible: !model.requests.is_empty(),
+
+            // Closing the window counts as "Cancel"
+            connect_close_request[sender] => move |_| {
+                sender.input(DialogMsg::Respond(false));
+                gtk::glib::Propagation::Stop
+            },
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+                set_spacing: 10,
+                set_margin_all: 10,
+
+                gtk::Label {
+                    set_wrap: true,
+                    #[watch]
+                    set_label: model.text(),
+                },
+
+                gtk::Box {
+                    set_spacing: 5,
+                    set_halign: gtk::Align::End,
+
+                    gtk::Button {
+                        set_label: "Cancel",
+                        #[watch]
+                        set_visible: model.is_confirmation(),
+                        connect_clicked => DialogMsg::Respond(false),
+                    },
+
+                    gtk::Button {
+                        set_label: "OK",
+                        add_css_class: "suggested-action",
+                        connect_clicked => DialogMsg::Respond(true),
+                    },
+                },
+            },
+        }
+    }
+
+    fn init(_: (), root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+        let model = Dialog {
+            requests: VecDeque::new(),
+        };
+        let widgets = view_output!();
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            DialogMsg::Show(request) => self.requests.push_back(request),
+            DialogMsg::Respond(confirmed) => match self.requests.pop_front() {
+                Some(Request::Confirm { reply, .. }) => (reply.0)(confirmed),
+                Some(Request::Error(_)) | None => (),
+            },
+        }
+    }
+}
+// ANCHOR_END: dialog
+
+// ANCHOR: counter
+#[derive(Debug)]
+struct Counter {
+    value: u8,
+}
+
+#[derive(Debug)]
+enum CounterMsg {
+    Increment,
+    AskReset,
+    Reset,
+}
+
+#[relm4::factory]
+impl FactoryComponent for Counter {
+    type Init = u8;
+    type Input = CounterMsg;
+    type Output = ();
+    type CommandOutput = ();
+    type ParentWidget = gtk::Box;
+
+    view! {
+        #[root]
+        gtk::Box {
+            set_orientation: gtk::Orientation::Horizontal,
+            set_spacing: 10,
+
+            gtk::Label {
+                #[watch]
+                set_label: &self.value.to_string(),
+                set_width_chars: 3,
+            },
+
+            gtk::Button {
+                set_label: "+",
+                connect_clicked => CounterMsg::Increment,
+            },
+
+            gtk::Button {
+                set_label: "Reset",
+                connect_clicked => CounterMsg::AskReset,
+            },
+        }
+    }
+
+    fn init_model(value: Self::Init, _index: &DynamicIndex, _sender: FactorySender<Self>) -> Self {
+        Self { value }
+    }
+
+    // ANCHOR: counter_update
+    fn update(&mut self, msg: Self::Input, sender: FactorySender<Self>) {
+        match msg {
+            CounterMsg::Increment => match self.value.checked_add(10) {
+                Some(value) => self.value = value,
+                // No output message and no forwarding in the parent needed
+                None => DIALOG_BROKER.send(DialogMsg::Show(Request::Error(format!(
+                    "The counter can't go higher than {}",
+                    u8::MAX
+                )))),
+            },
+            CounterMsg::AskReset => {
+                // The answer comes back as a regular input message
+                let input = sender.input_sender().clone();
+                DIALOG_BROKER.send(DialogMsg::Show(Request::Confirm {
+                    question: format!("Reset the counter from {} to 0?", self.value),
+                    reply: Reply::new(move |confirmed| {
+                        if confirmed {
+                            input.emit(CounterMsg::Reset);
+                        }
+                    }),
+                }));
+            }
+            CounterMsg::Reset => self.value = 0,
+        }
+    }
+    // ANCHOR_END: counter_update
+}
+// ANCHOR_END: counter
+
+// ANCHOR: app
+struct App {
+    counters: FactoryVecDeque<Counter>,
+    /// Dropping the controller shuts the dialog down.
+    /// Sending a message through the broker after that panics.
+    _dialog: Controller<Dialog>,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    AddCounter,
+}
+
+#[relm4::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = AppMsg;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            set_title: Some("Message broker"),
+            set_default_size: (300, 100),
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+                set_spacing: 5,
+                set_margin_all: 5,
+
+                gtk::Button {
+                    set_label: "Add counter",
+                    connect_clicked => AppMsg::AddCounter,
+                },
+
+                #[local_ref]
+                counter_box -> gtk::Box {
+                    set_orientation: gtk::Orientation::Vertical,
+                    set_spacing: 5,
+                },
+            },
+        }
+    }
+
+    // ANCHOR: app_init
+    fn init(_: (), root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+        // Messages sent through the broker before this point are not lost,
+        // they are queued until the dialog is launched. Still, launching it
+        // first makes sure it exists before any other component can use it.
+        let dialog = Dialog::builder()
+            .transient_for(&root)
+            .launch_with_broker((), &DIALOG_BROKER)
+            .detach();
+
+        let mut counters = FactoryVecDeque::builder()
+            .launch(gtk::Box::default())
+            .detach();
+        counters.guard().push_back(240);
+
+        let model = App {
+            counters,
+            _dialog: dialog,
+        };
+
+        let counter_box = model.counters.widget();
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+    // ANCHOR_END: app_init
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            AppMsg::AddCounter => {
+                self.counters.guard().push_back(0);
+            }
+        }
+    }
+}
+// ANCHOR_END: app
+
+fn main() {
+    let app = RelmApp::new("relm4.example.message_broker");
+    app.run::<App>(());
+}
+// ANCHOR_END: all
@@ -0,0 +1,365 @@
+// ANCHOR: all
+use gtk::prelude::{BoxExt, ButtonExt, GtkWindowExt, OrientableExt, WidgetExt};
+use relm4::{gtk, ComponentParts, ComponentSender, RelmApp, RelmWidgetExt, SimpleComponent};
+
+use inspector::Inspector;
+
+// ANCHOR: inspector
+mod inspector {
+    use std::cell::{Cell, RefCell};
+    use std::fmt::Debug;
+    use std::time::{Duration, Instant};
+
+    use gtk::glib;
+    use gtk::prelude::*;
+    use relm4::factory::{DynamicIndex, FactoryComponent, FactorySender, FactoryVecDeque};
+    use relm4::{
+        gtk, Component, ComponentController, ComponentParts, ComponentSender, Controller,
+        RelmWidgetExt, SimpleComponent,
+    };
+
+    /// Opens and closes the message log.
+    const SHORTCUT: &str = "<Control><Shift>m";
+    /// Older entries are removed, so the log doesn't grow forever.
+    const MAX_ENTRIES: usize = 500;
+
+    // ANCHOR: entry
+    /// A message that was processed by the inspected component.
+    #[derive(Debug)]
+    pub struct Entry {
+        /// Time since the inspector was created.
+        received: Duration,
+        message: String,
+        update: Duration,
+        update_view: Duration,
+    }
+    // ANCHOR_END: entry
+
+    // ANCHOR: api
+    /// Records the messages of a component and shows them in a separate window.
+    pub struct Inspector {
+        log: Controller<MessageLog>,
+        created: Instant,
+        /// The last message, waiting for the view to be updated.
+        pending: RefCell<Option<Entry>>,
+        view_started: Cell<Option<Instant>>,
+    }
+
+    impl Inspector {
+        /// Creates the log window and adds the keyboard shortcut to `window`.
+        pub fn new(window: &gtk::Window) -> Self {
+            let log = MessageLog::builder()
+                .transient_for(window)
+                .launch(())
+                .detach();
+
+            let log_sender = log.sender().clone();
+            let shortcut = gtk::Shortcut::new(
+                gtk::ShortcutTrigger::parse_string(SHORTCUT),
+                Some(gtk::CallbackAction::new(move |_, _| {
+                    log_sender.emit(LogMsg::Toggle);
+                    glib::Propagation::Stop
+                })),
+            );
+            let controller = gtk::ShortcutController::new();
+            controller.add_shortcut(shortcut);
+            window.add_controller(controller);
+
+            Self {
+                log,
+                created: Instant::now(),
+                pending: RefCell::default(),
+                view_started: Cell::default(),
+            }
+        }
+
+        /// Call at the start of `update`. The time is measured
+        /// until the returned guard is dropped at the end of `update`.
+        #[must_use]
+        pub fn update(&self, message: &impl Debug) -> UpdateGuard<'_> {
+            UpdateGuard {
+                inspector: self,
+                message: format!("{message:?}"),
+                started: Instant::now(),
+            }
+        }
+
+        /// Call in `pre_view`, or at the start of a hand-written `update_view`.
+        pub fn view_started(&self) {
+            self.view_started.set(Some(Instant::now()));
+        }
+
+        /// Call in `post_view`, or at the end of a hand-written `update_view`.
+        pub fn view_finished(&self) {
+            let (Some(mut entry), Some(started)) =
+                (self.pending.take(), self.view_started.take())
+            else {
+                return;
+            };
+            entry.update_view = started.elapsed();
+            self.log.emit(LogMsg::Add(entry));
+        }
+    }
+
+    pub struct UpdateGuard<'a> {
+        inspector: &'a Inspector,
+        message: String,
+        started: Instant,
+    }
+
+    impl Drop for UpdateGuard<'_> {
+        fn drop(&mut self) {
+            let entry = Entry {
+                received: self.started - self.inspector.created,
+                message: std::mem::take(&mut self.message),
+                update: self.started.elapsed(),
+                update_view: Duration::ZERO,
+            };
+            self.inspector.pending.replace(Some(entry));
+        }
+    }
+    // ANCHOR_END: api
+
+    // ANCHOR: log
+    fn format_duration(duration: Duration) -> String {
+        format!("{} µs", duration.as_micros())
+    }
+
+    #[relm4::factory]
+    impl FactoryComponent for Entry {
+        type Init = Entry;
+        type Input = ();
+        type Output = ();
+        type CommandOutput = ();
+        type ParentWidget = gtk::Box;
+
+        view! {
+            #[root]
+            gtk::Box {
+                set_orientation: gtk::Orientation::Horizontal,
+                set_spacing: 10,
+
+                gtk::Label {
+                    set_label: &format!("{:.3} s", self.received.as_secs_f64()),
+                    set_width_chars: 10,
+                    set_xalign: 1.0,
+                },
+                gtk::Label {
+                    set_label: &self.message,
+                    set_hexpand: true,
+                    set_xalign: 0.0,
+                    set_wrap: true,
+                    set_selectable: true,
+                    add_css_class: "monospace",
+                },
+                gtk::Label {
+                    set_label: &format_duration(self.update),
+                    set_tooltip_text: Some("update"),
+                    set_width_chars: 10,
+                    set_xalign: 1.0,
+                },
+                gtk::Label {
+                    set_label: &format_duration(self.update_view),
+                    set_tooltip_text: Some("update_view"),
+                    set_width_chars: 10,
+                    set_xalign: 1.0,
+                },
+            }
+        }
+
+        fn init_model(entry: Self::Init, _index: &DynamicIndex, _sender: FactorySender<Self>) -> Self {
+            entry
+        }
+    }
+
+    struct MessageLog {
+        entries: FactoryVecDeque<Entry>,
+        visible: bool,
+    }
+
+    #[derive(Debug)]
+    enum LogMsg {
+        Add(Entry),
+        Toggle,
+        Hide,
+        Clear,
+    }
+
+    #[relm4::component]
+    impl SimpleComponent for MessageLog {
+        type Init = ();
+        type Input = LogMsg;
+        type Output = ();
+
+        view! {
+            gtk::Window {
+                set_title: Some("Messages"),
+                set_default_size: (600, 400),
+                #[watch]
+                set_visible: model.visible,
+                connect_close_request[sender] => move |_| {
+                    sender.input(LogMsg::Hide);
+                    glib::Propagation::Stop
+                },
+
+                gtk::Box {
+                    set_orientation: gtk::Orientation::Vertical,
+                    set_spacing: 5,
+                    set_margin_all: 5,
+
+                    gtk::Box {
+                        set_spacing: 5,
+
+                        gtk::Label {
+                            set_hexpand: true,
+                            set_xalign: 0.0,
+                            #[watch]
+                            set_label: &format!("{} messages, newest first", model.entries.len()),
+                        },
+                        gtk::Button {
+                            set_label: "Clear",
+                            connect_clicked => LogMsg::Clear,
+                        },
+                    },
+
+                    gtk::ScrolledWindow {
+                        set_vexpand: true,
+
+                        #[local_ref]
+                        entry_box -> gtk::Box {
+                            set_orientation: gtk::Orientation::Vertical,
+                            set_spacing: 5,
+                        },
+                    },
+                },
+            }
+        }
+
+        fn init(_: (), root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+            let model = MessageLog {
+                entries: FactoryVecDeque::builder()
+                    .launch(gtk::Box::default())
+                    .detach(),
+                visible: false,
+            };
+
+            let entry_box = model.entries.widget();
+            let widgets = view_output!();
+
+            ComponentParts { model, widgets }
+        }
+
+        fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+            match msg {
+                LogMsg::Add(entry) => {
+                    let mut entries = self.entries.guard();
+                    entries.push_front(entry);
+                    if entries.len() > MAX_ENTRIES {
+                        entries.pop_back();
+                    }
+                }
+                LogMsg::Toggle => self.visible = !self.visible,
+                LogMsg::Hide => self.visible = false,
+                LogMsg::Clear => self.entries.guard().clear(),
+            }
+        }
+    }
+    // ANCHOR_END: log
+}
+// ANCHOR_END: inspector
+
+// ANCHOR: app
+struct AppModel {
+    counter: u8,
+    inspector: Inspector,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    Increment,
+    Decrement,
+}
+
+#[relm4::component]
+impl SimpleComponent for AppModel {
+    type Init = u8;
+    type Input = AppMsg;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            set_title: Some("Message inspector"),
+            set_default_width: 300,
+            set_default_height: 100,
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+                set_spacing: 5,
+                set_margin_all: 5,
+
+                gtk::Button {
+                    set_label: "Increment",
+                    connect_clicked => AppMsg::Increment,
+                },
+
+                gtk::Button::with_label("Decrement") {
+                    connect_clicked => AppMsg::Decrement,
+                },
+
+                gtk::Label {
+                    #[watch]
+                    set_label: &format!("Counter: {}", model.counter),
+                    set_margin_all: 5,
+                },
+
+                gtk::Label {
+                    set_label: "Press Ctrl+Shift+M to show the messages",
+                    add_css_class: "dim-label",
+                },
+            }
+        }
+    }
+
+    fn init(
+        counter: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = AppModel {
+            counter,
+            inspector: Inspector::new(&root),
+        };
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        let _guard = self.inspector.update(&msg);
+
+        match msg {
+            AppMsg::Increment => {
+                self.counter = self.counter.wrapping_add(1);
+            }
+            AppMsg::Decrement => {
+                self.counter = self.counter.wrapping_sub(1);
+            }
+        }
+    }
+
+    fn pre_view() {
+        model.inspector.view_started();
+    }
+
+    fn post_view() {
+        model.inspector.view_finished();
+    }
+}
+// ANCHOR_END: app
+
+fn main() {
+    let app = RelmApp::new("relm4.example.message_inspector");
+    app.run::<AppModel>(0);
+}
+// ANCHOR_END: all
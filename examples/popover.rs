@@ -0,0 +1,168 @@
+// ANCHOR: all
+use gtk::prelude::{BoxExt, GtkWindowExt, OrientableExt, WidgetExt};
+use relm4::actions::{AccelsPlus, RelmAction, RelmActionGroup};
+use relm4::{gtk, ComponentParts, ComponentSender, RelmApp, RelmWidgetExt, SimpleComponent};
+
+// ANCHOR: actions
+relm4::new_action_group!(WindowActionGroup, "win");
+
+// Actions without state are shown as regular menu items
+relm4::new_stateless_action!(IncrementAction, WindowActionGroup, "increment");
+relm4::new_stateless_action!(ResetAction, WindowActionGroup, "reset");
+
+// A boolean state without a target value is shown as a check box
+relm4::new_stateful_action!(ShowCounterAction, WindowActionGroup, "show-counter", (), bool);
+
+// A target value of the same type as the state is shown as a radio button
+relm4::new_stateful_action!(StepAction, WindowActionGroup, "step", u8, u8);
+// ANCHOR_END: actions
+
+// ANCHOR: model
+struct AppModel {
+    counter: u32,
+    show_counter: bool,
+    step: u8,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    Increment,
+    Reset,
+    ShowCounter(bool),
+    SetStep(u8),
+}
+// ANCHOR_END: model
+
+#[relm4::component]
+impl SimpleComponent for AppModel {
+    type Init = ();
+    type Input = AppMsg;
+    type Output = ();
+
+    // ANCHOR: view
+    view! {
+        main_window = gtk::Window {
+            set_title: Some("Popover menu"),
+            set_default_size: (300, 150),
+
+            #[wrap(Some)]
+            set_titlebar = &gtk::HeaderBar {
+                pack_end = &gtk::MenuButton {
+                    set_icon_name: "open-menu-symbolic",
+                    set_tooltip_text: Some("Menu"),
+                    set_menu_model: Some(&main_menu),
+                },
+            },
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+                set_valign: gtk::Align::Center,
+                set_margin_all: 5,
+                set_spacing: 5,
+
+                gtk::Label {
+                    add_css_class: "title-1",
+                    #[watch]
+                    set_visible: model.show_counter,
+                    #[watch]
+                    set_label: &model.counter.to_string(),
+                },
+
+                gtk::Label {
+                    #[watch]
+                    set_label: &format!("Step size: {}", model.step),
+                },
+            },
+        }
+    }
+
+    menu! {
+        main_menu: {
+            "Increment" => IncrementAction,
+            "Reset" => ResetAction,
+            section! {
+                "Show counter" => ShowCounterAction,
+            },
+            section! {
+                "Step by 1" => StepAction(1_u8),
+                "Step by 5" => StepAction(5_u8),
+                "Step by 10" => StepAction(10_u8),
+            },
+        }
+    }
+    // ANCHOR_END: view
+
+    // ANCHOR: init
+    fn init(_: (), root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+        let model = AppModel {
+            counter: 0,
+            show_counter: true,
+            step: 1,
+        };
+
+        let widgets = view_output!();
+
+        let increment: RelmAction<IncrementAction> = {
+            let sender = sender.clone();
+            RelmAction::new_stateless(move |_| {
+                sender.input(AppMsg::Increment);
+            })
+        };
+
+        let reset: RelmAction<ResetAction> = {
+            let sender = sender.clone();
+            RelmAction::new_stateless(move |_| {
+                sender.input(AppMsg::Reset);
+            })
+        };
+
+        // Stateful actions store their own copy of the state,
+        // which is used to draw the check box or radio button.
+        // The model is updated with a message, just like for any other widget.
+        let show_counter: RelmAction<ShowCounterAction> = {
+            let sender = sender.clone();
+            RelmAction::new_stateful(&model.show_counter, move |_, state: &mut bool| {
+                *state = !*state;
+                sender.input(AppMsg::ShowCounter(*state));
+            })
+        };
+
+        let step: RelmAction<StepAction> = RelmAction::new_stateful_with_target_value(
+            &model.step,
+            move |_, state: &mut u8, value: u8| {
+                *state = value;
+                sender.input(AppMsg::SetStep(value));
+            },
+        );
+
+        let mut group = RelmActionGroup::<WindowActionGroup>::new();
+        group.add_action(increment);
+        group.add_action(reset);
+        group.add_action(show_counter);
+        group.add_action(step);
+        group.register_for_widget(&widgets.main_window);
+
+        ComponentParts { model, widgets }
+    }
+    // ANCHOR_END: init
+
+    // ANCHOR: update
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            AppMsg::Increment => self.counter += u32::from(self.step),
+            AppMsg::Reset => self.counter = 0,
+            AppMsg::ShowCounter(show_counter) => self.show_counter = show_counter,
+            AppMsg::SetStep(step) => self.step = step,
+        }
+    }
+    // ANCHOR_END: update
+}
+
+// ANCHOR: main
+fn main() {
+    let app = RelmApp::new("relm4.example.popover");
+    relm4::main_application().set_accelerators_for_action::<IncrementAction>(&["<primary>plus"]);
+    app.run::<AppModel>(());
+}
+// ANCHOR_END: main
+// ANCHOR_END: all
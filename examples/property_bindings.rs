@@ -0,0 +1,179 @@
+// ANCHOR: all
+use gtk::prelude::*;
+use relm4::{gtk, ComponentParts, ComponentSender, RelmApp, RelmWidgetExt, SimpleComponent};
+
+// ANCHOR: model
+struct AppModel {
+    /// Only used by the `#[watch]` side, the bound widgets don't need the model.
+    value: f64,
+    revealed: bool,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    SetValue(f64),
+    SetRevealed(bool),
+}
+// ANCHOR_END: model
+
+#[relm4::component]
+impl SimpleComponent for AppModel {
+    type Init = ();
+    type Input = AppMsg;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            set_title: Some("Property bindings"),
+            set_default_width: 600,
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Horizontal,
+                set_spacing: 10,
+                set_margin_all: 10,
+                set_homogeneous: true,
+
+                // ANCHOR: bound_view
+                gtk::Frame {
+                    set_label: Some("GObject bindings"),
+
+                    gtk::Box {
+                        set_orientation: gtk::Orientation::Vertical,
+                        set_spacing: 5,
+                        set_margin_all: 5,
+
+                        #[name = "bound_scale"]
+                        gtk::Scale::with_range(gtk::Orientation::Horizontal, 0.0, 100.0, 1.0) {},
+
+                        #[name = "bound_spin"]
+                        gtk::SpinButton::with_range(0.0, 1.0, 0.01) {},
+
+                        #[name = "bound_label"]
+                        gtk::Label {},
+
+                        #[name = "bound_switch"]
+                        gtk::Switch {
+                            set_halign: gtk::Align::Start,
+                        },
+
+                        #[name = "bound_revealer"]
+                        gtk::Revealer {
+                            gtk::Label {
+                                set_label: "Revealed by a binding",
+                            },
+                        },
+                    },
+                },
+                // ANCHOR_END: bound_view
+
+                // ANCHOR: watch_view
+                gtk::Frame {
+                    set_label: Some("Relm4 update loop"),
+
+                    gtk::Box {
+                        set_orientation: gtk::Orientation::Vertical,
+                        set_spacing: 5,
+                        set_margin_all: 5,
+
+                        gtk::Scale::with_range(gtk::Orientation::Horizontal, 0.0, 100.0, 1.0) {
+                            #[watch]
+                            set_value: model.value,
+                            connect_value_changed[sender] => move |scale| {
+                                sender.input(AppMsg::SetValue(scale.value()));
+                            },
+                        },
+
+                        gtk::SpinButton::with_range(0.0, 1.0, 0.01) {
+                            #[watch]
+                            set_value: model.value / 100.0,
+                            connect_value_changed[sender] => move |spin| {
+                                sender.input(AppMsg::SetValue(spin.value() * 100.0));
+                            },
+                        },
+
+                        gtk::Label {
+                            #[watch]
+                            set_label: &format!("{:.0} %", model.value),
+                        },
+
+                        gtk::Switch {
+                            set_halign: gtk::Align::Start,
+                            #[watch]
+                            set_active: model.revealed,
+                            connect_active_notify[sender] => move |switch| {
+                                sender.input(AppMsg::SetRevealed(switch.is_active()));
+                            },
+                        },
+
+                        gtk::Revealer {
+                            #[watch]
+                            set_reveal_child: model.revealed,
+
+                            gtk::Label {
+                                set_label: "Revealed by the model",
+                            },
+                        },
+                    },
+                },
+                // ANCHOR_END: watch_view
+            }
+        }
+    }
+
+    fn init(_: (), root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+        let model = AppModel {
+            value: 50.0,
+            revealed: false,
+        };
+
+        let widgets = view_output!();
+
+        // ANCHOR: bindings
+        // The scale stores its value in an adjustment. Changing either the scale or
+        // the spin button updates the other one, converting between the two ranges.
+        widgets
+            .bound_scale
+            .adjustment()
+            .bind_property("value", &widgets.bound_spin, "value")
+            .transform_to(|_, value: f64| Some(value / 100.0))
+            .transform_from(|_, value: f64| Some(value * 100.0))
+            .bidirectional()
+            .build();
+        widgets.bound_scale.set_value(model.value);
+
+        // One-way binding that turns the number into a string.
+        // `sync_create` sets the label right away instead of waiting for the next change.
+        widgets
+            .bound_scale
+            .adjustment()
+            .bind_property("value", &widgets.bound_label, "label")
+            .transform_to(|_, value: f64| Some(format!("{value:.0} %")))
+            .sync_create()
+            .build();
+
+        // Properties of the same type don't need a transform function.
+        widgets
+            .bound_switch
+            .bind_property("active", &widgets.bound_revealer, "reveal-child")
+            .sync_create()
+            .build();
+        // ANCHOR_END: bindings
+
+        ComponentParts { model, widgets }
+    }
+
+    // ANCHOR: update
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            AppMsg::SetValue(value) => self.value = value,
+            AppMsg::SetRevealed(revealed) => self.revealed = revealed,
+        }
+    }
+    // ANCHOR_END: update
+}
+
+fn main() {
+    let app = RelmApp::new("relm4.example.property_bindings");
+    app.run::<AppModel>(());
+}
+// ANCHOR_END: all
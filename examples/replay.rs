@@ -0,0 +1,430 @@
+// ANCHOR: all
+use std::collections::VecDeque;
+use std::fmt;
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser};
+use gtk::glib;
+use gtk::prelude::{BoxExt, ButtonExt, GtkWindowExt, OrientableExt, WidgetExt};
+use relm4::factory::{DynamicIndex, FactoryComponent, FactorySender, FactoryVecDeque};
+use relm4::{gtk, ComponentParts, ComponentSender, RelmApp, RelmWidgetExt, SimpleComponent};
+
+// ANCHOR: event
+/// Everything that changes the state of the app.
+///
+/// Unlike `DynamicIndex`, plain indices can be written to a file.
+/// They are taken when the message is sent, which is the position the user clicked on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Event {
+    AddCounter,
+    RemoveCounter,
+    Increment(usize),
+    Decrement(usize),
+    SendFront(usize),
+    MoveUp(usize),
+    MoveDown(usize),
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AddCounter => write!(f, "add"),
+            Self::RemoveCounter => write!(f, "remove"),
+            Self::Increment(index) => write!(f, "increment {index}"),
+            Self::Decrement(index) => write!(f, "decrement {index}"),
+            Self::SendFront(index) => write!(f, "front {index}"),
+            Self::MoveUp(index) => write!(f, "up {index}"),
+            Self::MoveDown(index) => write!(f, "down {index}"),
+        }
+    }
+}
+
+impl FromStr for Event {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        let name = parts.next().unwrap_or_default();
+        let mut index = || -> Result<usize, String> {
+            let index = parts.next().ok_or("missing index")?;
+            index
+                .parse()
+                .map_err(|_| format!("invalid index `{index}`"))
+        };
+
+        Ok(match name {
+            "add" => Self::AddCounter,
+            "remove" => Self::RemoveCounter,
+            "increment" => Self::Increment(index()?),
+            "decrement" => Self::Decrement(index()?),
+            "front" => Self::SendFront(index()?),
+            "up" => Self::MoveUp(index()?),
+            "down" => Self::MoveDown(index()?),
+            _ => return Err(format!("unknown event `{name}`")),
+        })
+    }
+}
+// ANCHOR_END: event
+
+// ANCHOR: recorder
+/// Writes each event with the time since the start of the session.
+struct Recorder<W: Write> {
+    writer: W,
+    started: Instant,
+}
+
+impl<W: Write> Recorder<W> {
+    fn new(writer: W) -> Self {
+        Self {
+            writer,
+            started: Instant::now(),
+        }
+    }
+
+    /// Each line is written right away, so the recording survives a crash.
+    fn record(&mut self, event: Event) -> std::io::Result<()> {
+        let millis = self.started.elapsed().as_millis();
+        writeln!(self.writer, "{millis} {event}")?;
+        self.writer.flush()
+    }
+}
+
+/// Reads a recording made by [`Recorder`].
+fn parse_recording(text: &str) -> Result<Vec<(Duration, Event)>, String> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(number, line)| {
+            let error = |msg: String| format!("line {}: {msg}", number + 1);
+            let (millis, event) = line
+                .split_once(' ')
+                .ok_or_else(|| error("missing event".into()))?;
+            let millis = millis
+                .parse()
+                .map_err(|_| error(format!("invalid time `{millis}`")))?;
+            Ok((Duration::from_millis(millis), event.parse().map_err(error)?))
+        })
+        .collect()
+}
+// ANCHOR_END: recorder
+
+// ANCHOR: state
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CounterData {
+    id: u8,
+    value: u8,
+}
+
+/// The state of the app without any widgets, so it can be compared in tests.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct CounterList {
+    counters: VecDeque<CounterData>,
+    created: u8,
+}
+
+impl CounterList {
+    fn apply(&mut self, event: Event) {
+        match event {
+            Event::AddCounter => {
+                self.counters.push_back(CounterData {
+                    id: self.created,
+                    value: self.created,
+                });
+                self.created = self.created.wrapping_add(1);
+            }
+            Event::RemoveCounter => {
+                self.counters.pop_back();
+            }
+            Event::Increment(index) => {
+                if let Some(counter) = self.counters.get_mut(index) {
+                    counter.value = counter.value.wrapping_add(1);
+                }
+            }
+            Event::Decrement(index) => {
+                if let Some(counter) = self.counters.get_mut(index) {
+                    counter.value = counter.value.wrapping_sub(1);
+                }
+            }
+            Event::SendFront(index) => {
+                if let Some(counter) = self.counters.remove(index) {
+                    self.counters.push_front(counter);
+                }
+            }
+            Event::MoveUp(index) => {
+                if index != 0 && index < self.counters.len() {
+                    self.counters.swap(index, index - 1);
+                }
+            }
+            Event::MoveDown(index) => {
+                if index + 1 < self.counters.len() {
+                    self.counters.swap(index, index + 1);
+                }
+            }
+        }
+    }
+}
+// ANCHOR_END: state
+
+// ANCHOR: counter
+/// Shows a counter. The state is owned by the parent, so every click is recorded.
+struct Counter {
+    data: CounterData,
+}
+
+#[relm4::factory]
+impl FactoryComponent for Counter {
+    type Init = CounterData;
+    type Input = ();
+    type Output = Event;
+    type CommandOutput = ();
+    type ParentWidget = gtk::Box;
+
+    view! {
+        #[root]
+        gtk::Box {
+            set_orientation: gtk::Orientation::Horizontal,
+            set_spacing: 10,
+            #[watch]
+            set_tooltip_text: Some(&format!("Counter {}", self.data.id)),
+
+            gtk::Label {
+                #[watch]
+                set_label: &self.data.value.to_string(),
+                set_width_chars: 3,
+            },
+
+            gtk::Button {
+                set_label: "+",
+                connect_clicked[sender, index] => move |_| {
+                    sender.output(Event::Increment(index.current_index())).unwrap();
+                },
+            },
+
+            gtk::Button {
+                set_label: "-",
+                connect_clicked[sender, index] => move |_| {
+                    sender.output(Event::Decrement(index.current_index())).unwrap();
+                },
+            },
+
+            gtk::Button {
+                set_label: "Up",
+                connect_clicked[sender, index] => move |_| {
+                    sender.output(Event::MoveUp(index.current_index())).unwrap();
+                },
+            },
+
+            gtk::Button {
+                set_label: "Down",
+                connect_clicked[sender, index] => move |_| {
+                    sender.output(Event::MoveDown(index.current_index())).unwrap();
+                },
+            },
+
+            gtk::Button {
+                set_label: "To Start",
+                connect_clicked[sender, index] => move |_| {
+                    sender.output(Event::SendFront(index.current_index())).unwrap();
+                },
+            },
+        }
+    }
+
+    fn init_model(data: Self::Init, _index: &DynamicIndex, _sender: FactorySender<Self>) -> Self {
+        Self { data }
+    }
+}
+// ANCHOR_END: counter
+
+// ANCHOR: app_model
+struct Init {
+    record: File,
+    replay: Option<Vec<(Duration, Event)>>,
+}
+
+struct App {
+    list: CounterList,
+    counters: FactoryVecDeque<Counter>,
+    recorder: Recorder<File>,
+    /// Number of events that are still waiting to be replayed.
+    replaying: usize,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    User(Event),
+    Replay(Event),
+}
+// ANCHOR_END: app_model
+
+impl App {
+    /// Updates the factory to show the list, only touching counters that changed.
+    fn sync_view(&mut self) {
+        let mut guard = self.counters.guard();
+        while guard.len() > self.list.counters.len() {
+            guard.pop_back();
+        }
+        for (index, data) in self.list.counters.iter().enumerate() {
+            match guard.get(index).map(|counter| counter.data != *data) {
+                Some(true) => guard.get_mut(index).unwrap().data = data.clone(),
+                Some(false) => (),
+                None => {
+                    guard.push_back(data.clone());
+                }
+            }
+        }
+    }
+}
+
+#[relm4::component]
+impl SimpleComponent for App {
+    type Init = Init;
+    type Input = AppMsg;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            set_title: Some("Record and replay"),
+            set_default_size: (300, 100),
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+                set_spacing: 5,
+                set_margin_all: 5,
+
+                gtk::Button {
+                    set_label: "Add counter",
+                    #[watch]
+                    set_sensitive: model.replaying == 0,
+                    connect_clicked => AppMsg::User(Event::AddCounter),
+                },
+
+                gtk::Button {
+                    set_label: "Remove counter",
+                    #[watch]
+                    set_sensitive: model.replaying == 0,
+                    connect_clicked => AppMsg::User(Event::RemoveCounter),
+                },
+
+                #[local_ref]
+                counter_box -> gtk::Box {
+                    set_orientation: gtk::Orientation::Vertical,
+                    set_spacing: 5,
+                    #[watch]
+                    set_sensitive: model.replaying == 0,
+                },
+
+                gtk::Label {
+                    #[watch]
+                    set_label: &if model.replaying == 0 {
+                        String::from("Recording")
+                    } else {
+                        format!("Replaying, {} events left", model.replaying)
+                    },
+                },
+            }
+        }
+    }
+
+    // ANCHOR: init
+    fn init(
+        init: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let counters = FactoryVecDeque::builder()
+            .launch(gtk::Box::default())
+            .forward(sender.input_sender(), AppMsg::User);
+
+        // The recording and the replay both start now, so they use the same offsets
+        let replay = init.replay.unwrap_or_default();
+        let mut model = App {
+            list: CounterList::default(),
+            counters,
+            recorder: Recorder::new(init.record),
+            replaying: replay.len(),
+        };
+        model.sync_view();
+
+        // Schedule all events at their original time after the start
+        for (time, event) in replay {
+            let sender = sender.clone();
+            glib::timeout_add_local_once(time, move || sender.input(AppMsg::Replay(event)));
+        }
+
+        let counter_box = model.counters.widget();
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+    // ANCHOR_END: init
+
+    // ANCHOR: update
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        let event = match msg {
+            // Clicks during a replay would change the outcome
+            AppMsg::User(_) if self.replaying > 0 => return,
+            AppMsg::User(event) => event,
+            AppMsg::Replay(event) => {
+                self.replaying -= 1;
+                event
+            }
+        };
+
+        // Replayed events are recorded as well, so both recordings can be compared
+        if let Err(err) = self.recorder.record(event) {
+            eprintln!("Could not record {event}: {err}");
+        }
+        self.list.apply(event);
+        self.sync_view();
+    }
+    // ANCHOR_END: update
+}
+
+// ANCHOR: main
+#[derive(Parser)]
+struct Args {
+    /// File the session is recorded to
+    #[arg(long, default_value = "session.txt")]
+    record: PathBuf,
+
+    /// Replays a recorded session at its original timing
+    #[arg(long)]
+    replay: Option<PathBuf>,
+}
+
+fn main() {
+    let args = Args::parse();
+
+    // Read the recording first, in case it's the same file we record to
+    let replay = args.replay.map(|path| {
+        std::fs::read_to_string(&path)
+            .map_err(|err| err.to_string())
+            .and_then(|text| parse_recording(&text))
+            .unwrap_or_else(|err| {
+                Args::command()
+                    .error(ErrorKind::Io, format!("{}: {err}", path.display()))
+                    .exit()
+            })
+    });
+
+    let file = File::create(&args.record).unwrap_or_else(|err| {
+        Args::command()
+            .error(ErrorKind::Io, format!("{}: {err}", args.record.display()))
+            .exit()
+    });
+
+    // GTK shouldn't parse the arguments again
+    let app = RelmApp::new("relm4.example.replay").with_args(Vec::new());
+    app.run::<App>(Init {
+        record: file,
+        replay,
+    });
+}
+// ANCHOR_END: main
+// ANCHOR_END: all
@@ -0,0 +1,516 @@
+//! Renders the screenshots in `src/img/screenshots` from the examples.
+//!
+//! The examples are compiled into this binary and their windows are rendered
+//! with the cairo renderer, so the result doesn't depend on the graphics driver.
+
+use std::any::Any;
+use std::error::Error;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use clap::Parser;
+use relm4::gtk::prelude::*;
+use relm4::gtk::{gdk, glib, graphene, gsk};
+use relm4::{adw, gtk};
+
+// The examples are included as modules, which keeps their private items accessible
+
+#[allow(dead_code)]
+mod simple {
+    include!("../../simple.rs");
+
+    use relm4::{Component as _, ComponentController as _};
+
+    pub fn scene() -> super::Scene {
+        let app = AppModel::builder().launch(0).detach();
+        super::Scene::new(vec![app.widget().clone()], app)
+    }
+}
+
+#[allow(dead_code)]
+mod components {
+    include!("../../components.rs");
+
+    use relm4::gtk::prelude::Cast as _;
+
+    pub fn main_window() -> super::Scene {
+        let app = AppModel::builder().launch(AppMode::Edit).detach();
+        super::Scene::new(vec![app.widget().clone()], app)
+    }
+
+    pub fn close_dialog() -> super::Scene {
+        let app = AppModel::builder().launch(AppMode::Edit).detach();
+        app.emit(AppMsg::CloseRequest);
+        super::pump();
+
+        let dialog = app.model().dialog.widget().clone().upcast();
+        super::Scene::new(vec![app.widget().clone(), dialog], app)
+    }
+}
+
+#[allow(dead_code)]
+mod factory {
+    include!("../../factory.rs");
+
+    use relm4::{Component as _, ComponentController as _};
+
+    pub fn scene() -> super::Scene {
+        let app = App::builder().launch(0).detach();
+        for _ in 0..3 {
+            app.emit(AppMsg::AddCounter);
+        }
+        super::pump();
+
+        super::Scene::new(vec![app.widget().clone()], app)
+    }
+}
+
+#[allow(dead_code)]
+mod tracker {
+    include!("../../tracker.rs");
+
+    use std::sync::Once;
+
+    use relm4::gtk::prelude::Cast as _;
+    use relm4::{Component as _, ComponentController as _};
+
+    /// The icons are random, so the buttons are "clicked" until the wanted icons show up.
+    fn scene(first: &str, second: &str) -> super::Scene {
+        // The example sets the CSS in its main function
+        static CSS: Once = Once::new();
+        CSS.call_once(|| relm4::set_global_css(".identical { background: #00ad5c; }"));
+
+        let app = AppModel::builder().launch(()).detach();
+        while app.model().first_icon != first {
+            app.emit(AppInput::UpdateFirst);
+            super::pump();
+        }
+        while app.model().second_icon != second {
+            app.emit(AppInput::UpdateSecond);
+            super::pump();
+        }
+
+        super::Scene::new(vec![app.widget().clone().upcast()], app)
+    }
+
+    pub fn different() -> super::Scene {
+        scene(ICON_LIST[0], ICON_LIST[1])
+    }
+
+    pub fn identical() -> super::Scene {
+        scene(ICON_LIST[0], ICON_LIST[0])
+    }
+}
+
+#[allow(dead_code)]
+mod alert {
+    include!("../../alert.rs");
+
+    pub fn first_alert() -> super::Scene {
+        let app = App::builder().launch(()).detach();
+        app.emit(AppMsg::CloseRequest);
+        super::pump();
+
+        let dialog = app.model().dialog.widget().clone().upcast();
+        super::Scene::new(vec![app.widget().clone().upcast(), dialog], app)
+    }
+
+    pub fn second_alert() -> super::Scene {
+        let app = App::builder().launch(()).detach();
+        app.emit(AppMsg::CloseRequest);
+        super::pump();
+
+        // The app alternates between both alerts
+        app.model()
+            .dialog
+            .emit(AlertMsg::Response(gtk::ResponseType::Cancel));
+        super::pump();
+        app.emit(AppMsg::CloseRequest);
+        super::pump();
+
+        let dialog = app.model().second_dialog.widget().clone().upcast();
+        super::Scene::new(vec![app.widget().clone().upcast(), dialog], app)
+    }
+}
+
+#[allow(dead_code)]
+mod popover {
+    include!("../../popover.rs");
+
+    use relm4::{Component as _, ComponentController as _};
+
+    /// The window with its menu opened.
+    pub fn scene() -> super::Scene {
+        let app = AppModel::builder().launch(()).detach();
+        let window = app.widget().clone();
+        let popover = super::find::<gtk::MenuButton>(&window)
+            .first()
+            .and_then(|button| button.popover())
+            .expect("The example has a menu button");
+
+        super::Scene::new(vec![window], app).with_popover(popover)
+    }
+}
+
+#[allow(dead_code)]
+mod simple_manual {
+    include!("../../libadwaita/simple_manual.rs");
+
+    use relm4::gtk::prelude::Cast as _;
+    use relm4::{Component as _, ComponentController as _};
+
+    pub fn scene() -> super::Scene {
+        let app = AppModel::builder().launch(0).detach();
+        super::Scene::new(vec![app.widget().clone().upcast()], app)
+    }
+}
+
+// ANCHOR: scene
+/// The windows shown in a screenshot, from bottom to top.
+struct Scene {
+    windows: Vec<gtk::Window>,
+    /// Popovers that are opened on top of their window.
+    popovers: Vec<gtk::Popover>,
+    /// Keeps the components running until the screenshot was taken.
+    _controller: Box<dyn Any>,
+}
+
+impl Scene {
+    fn new(windows: Vec<gtk::Window>, controller: impl Any) -> Self {
+        Self {
+            windows,
+            popovers: Vec::new(),
+            _controller: Box::new(controller),
+        }
+    }
+
+    fn with_popover(mut self, popover: gtk::Popover) -> Self {
+        self.popovers.push(popover);
+        self
+    }
+
+    /// Renders all windows on top of each other, centered like dialogs, and saves them as PNG.
+    /// Popovers are drawn where the display placed them, even if they reach beyond their window.
+    fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        for window in &self.windows {
+            show(window)?;
+        }
+        for popover in &self.popovers {
+            popover.popup();
+            wait_until_mapped(popover)?;
+        }
+
+        let width = self
+            .windows
+            .iter()
+            .map(|window| window.width())
+            .max()
+            .unwrap_or_default();
+        let height = self
+            .windows
+            .iter()
+            .map(|window| window.height())
+            .max()
+            .unwrap_or_default();
+
+        // Every widget that is drawn, with its position
+        let mut layers: Vec<(gtk::Widget, (f64, f64))> = Vec::new();
+        for window in &self.windows {
+            let origin = (
+                f64::from((width - window.width()) / 2),
+                f64::from((height - window.height()) / 2),
+            );
+            layers.push((window.clone().upcast(), origin));
+
+            for popover in &self.popovers {
+                if popover
+                    .root()
+                    .is_some_and(|root| root == *window.upcast_ref())
+                {
+                    let (x, y) =
+                        popover_offset(window, popover).ok_or("The popover wasn't shown")?;
+                    layers.push((popover.clone().upcast(), (origin.0 + x, origin.1 + y)));
+                }
+            }
+        }
+
+        let left = layers.iter().map(|(_, (x, _))| *x).fold(0.0, f64::min);
+        let top = layers.iter().map(|(_, (_, y))| *y).fold(0.0, f64::min);
+        let right = layers
+            .iter()
+            .map(|(widget, (x, _))| x + f64::from(widget.width()))
+            .fold(f64::from(width), f64::max);
+        let bottom = layers
+            .iter()
+            .map(|(widget, (_, y))| y + f64::from(widget.height()))
+            .fold(f64::from(height), f64::max);
+
+        let snapshot = gtk::Snapshot::new();
+        for (widget, (x, y)) in &layers {
+            snapshot.save();
+            snapshot.translate(&graphene::Point::new((x - left) as f32, (y - top) as f32));
+            gtk::WidgetPaintable::new(Some(widget)).snapshot(
+                &snapshot,
+                widget.width().into(),
+                widget.height().into(),
+            );
+            snapshot.restore();
+        }
+        let node = snapshot.to_node().ok_or("Nothing was rendered")?;
+
+        // The cairo renderer works without a GPU and gives the same result everywhere
+        let renderer = gsk::CairoRenderer::new();
+        renderer.realize(None::<&gdk::Surface>)?;
+        let texture = renderer.render_texture(
+            &node,
+            Some(&graphene::Rect::new(
+                0.0,
+                0.0,
+                (right - left) as f32,
+                (bottom - top) as f32,
+            )),
+        );
+        renderer.unrealize();
+
+        texture.save_to_png(path)?;
+        Ok(())
+    }
+}
+
+impl Drop for Scene {
+    fn drop(&mut self) {
+        for window in self.windows.iter().rev() {
+            window.destroy();
+        }
+    }
+}
+// ANCHOR_END: scene
+
+/// Processes everything that's waiting in the main loop.
+fn pump() {
+    let context = glib::MainContext::default();
+    while context.pending() {
+        context.iteration(false);
+    }
+}
+
+/// How long a window may take to appear on the display.
+const SHOW_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Presents a window and waits until it's drawn.
+fn show(window: &gtk::Window) -> Result<(), Box<dyn Error>> {
+    window.present();
+    wait_until_mapped(window)
+}
+
+/// Widgets are only rendered once their surface is mapped by the display,
+/// which never happens if the display doesn't work.
+fn wait_until_mapped(widget: &impl IsA<gtk::Widget>) -> Result<(), Box<dyn Error>> {
+    let deadline = Instant::now() + SHOW_TIMEOUT;
+    while !widget.is_mapped() {
+        if Instant::now() > deadline {
+            return Err(format!("The widget wasn't shown within {SHOW_TIMEOUT:?}").into());
+        }
+        pump();
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    pump();
+    Ok(())
+}
+
+/// Returns where a popover is drawn relative to its window.
+///
+/// A popover has its own surface, which the display positions relative to
+/// the surface of the window. Both surfaces have room for shadows around the widgets.
+fn popover_offset(window: &gtk::Window, popover: &gtk::Popover) -> Option<(f64, f64)> {
+    let popup = popover.surface()?.downcast::<gdk::Popup>().ok()?;
+    let (window_x, window_y) = window.surface_transform();
+    let (popover_x, popover_y) = popover.surface_transform();
+    Some((
+        f64::from(popup.position_x()) + popover_x - window_x,
+        f64::from(popup.position_y()) + popover_y - window_y,
+    ))
+}
+
+/// Returns all widgets of type `W` below `root`, in the order they appear.
+fn find<W: IsA<gtk::Widget>>(root: &impl IsA<gtk::Widget>) -> Vec<W> {
+    let mut found = Vec::new();
+    let mut stack = vec![root.clone().upcast::<gtk::Widget>()];
+
+    while let Some(widget) = stack.pop() {
+        let mut children = Vec::new();
+        let mut child = widget.first_child();
+        while let Some(current) = child {
+            child = current.next_sibling();
+            children.push(current);
+        }
+        // Children are pushed in reverse, so the first child is visited first
+        stack.extend(children.into_iter().rev());
+
+        if let Ok(widget) = widget.downcast::<W>() {
+            found.push(widget);
+        }
+    }
+    found
+}
+
+/// The font of all screenshots. A different font changes the size of every widget.
+const FONT_FAMILY: &str = "Cantarell";
+
+fn has_font(family: &str) -> bool {
+    gtk::Label::new(None)
+        .pango_context()
+        .list_families()
+        .iter()
+        .any(|installed| installed.name().eq_ignore_ascii_case(family))
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Style {
+    Light,
+    Dark,
+    AdwLight,
+    AdwDark,
+}
+
+fn apply_style(style: Style) {
+    let settings = gtk::Settings::default().expect("No default display");
+    match style {
+        Style::Light | Style::Dark => {
+            settings.set_gtk_application_prefer_dark_theme(matches!(style, Style::Dark));
+        }
+        Style::AdwLight | Style::AdwDark => {
+            // libadwaita warns about this setting. It can't be unloaded either,
+            // which is why all libadwaita screenshots are taken last.
+            settings.set_gtk_application_prefer_dark_theme(false);
+            adw::init().expect("Could not initialize libadwaita");
+            adw::StyleManager::default().set_color_scheme(match style {
+                Style::AdwDark => adw::ColorScheme::ForceDark,
+                _ => adw::ColorScheme::ForceLight,
+            });
+        }
+    }
+    pump();
+}
+
+/// The file name without extension, the style and the scene of every screenshot.
+const SCREENSHOTS: &[(&str, Style, fn() -> Scene)] = &[
+    ("simple-light", Style::Light, simple::scene),
+    ("simple-dark", Style::Dark, simple::scene),
+    ("components-light-1", Style::Light, components::main_window),
+    ("components-dark-1", Style::Dark, components::main_window),
+    ("components-light-2", Style::Light, components::close_dialog),
+    ("components-dark-2", Style::Dark, components::close_dialog),
+    ("factory-dark", Style::Dark, factory::scene),
+    ("tracker-light-1", Style::Light, tracker::different),
+    ("tracker-dark-1", Style::Dark, tracker::different),
+    ("tracker-light-2", Style::Light, tracker::identical),
+    ("tracker-dark-2", Style::Dark, tracker::identical),
+    ("reusable-alert-light-1", Style::Light, alert::first_alert),
+    ("reusable-alert-dark-1", Style::Dark, alert::first_alert),
+    ("reusable-alert-light-2", Style::Light, alert::second_alert),
+    ("reusable-alert-dark-2", Style::Dark, alert::second_alert),
+    ("popover-light", Style::Light, popover::scene),
+    ("popover-dark", Style::Dark, popover::scene),
+    // libadwaita is initialized by the first of these
+    ("simple-light-adw", Style::AdwLight, simple::scene),
+    ("simple-dark-adw", Style::AdwDark, simple::scene),
+    ("adw_simple_manual", Style::AdwLight, simple_manual::scene),
+];
+
+/// The display of the private broadway server.
+const BROADWAY_DISPLAY: &str = ":42";
+
+/// A broadway server that only the generator draws on, so no window shows up on the desktop.
+///
+/// GTK only renders widgets of mapped windows, so they need a display, but nobody has to see it.
+struct Broadway(Child);
+
+impl Broadway {
+    /// Starts the server and makes GTK use it.
+    fn start() -> Result<Self, Box<dyn Error>> {
+        let mut child = Command::new("gtk4-broadwayd")
+            .arg(BROADWAY_DISPLAY)
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(|err| {
+                format!("Couldn't start gtk4-broadwayd, which comes with GTK 4: {err}")
+            })?;
+        let stdout = child.stdout.take().ok_or("gtk4-broadwayd has no output")?;
+        // Stops the server if it doesn't start properly
+        let server = Self(child);
+
+        // SAFETY: No other threads are running yet
+        unsafe {
+            std::env::set_var("GDK_BACKEND", "broadway");
+            std::env::set_var("BROADWAY_DISPLAY", BROADWAY_DISPLAY);
+        }
+
+        // The server prints where it listens once it accepts connections,
+        // and exits without output if the display is taken
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            let mut line = String::new();
+            let _ = BufReader::new(stdout).read_line(&mut line);
+            let _ = sender.send(line);
+        });
+        match receiver.recv_timeout(SHOW_TIMEOUT) {
+            Ok(line) if !line.is_empty() => Ok(server),
+            Ok(_) => {
+                Err(format!("gtk4-broadwayd couldn't use the display {BROADWAY_DISPLAY}").into())
+            }
+            Err(_) => Err(format!("gtk4-broadwayd didn't start within {SHOW_TIMEOUT:?}").into()),
+        }
+    }
+}
+
+impl Drop for Broadway {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+#[derive(Parser)]
+struct Args {
+    /// Directory the screenshots are written to
+    #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/../../src/img/screenshots"))]
+    out_dir: PathBuf,
+
+    /// Only takes the screenshots whose name contains this text
+    filter: Option<String>,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+
+    let _broadway = Broadway::start()?;
+    gtk::init()?;
+    let settings = gtk::Settings::default().ok_or("No default display")?;
+    // Don't use the theme, icons and font of the desktop
+    settings.set_gtk_theme_name(Some("Default"));
+    settings.set_gtk_icon_theme_name(Some("Adwaita"));
+    if !has_font(FONT_FAMILY) {
+        return Err(format!("The font {FONT_FAMILY} is not installed").into());
+    }
+    settings.set_gtk_font_name(Some(&format!("{FONT_FAMILY} 11")));
+    // Buttons shouldn't be caught in the middle of a transition
+    settings.set_gtk_enable_animations(false);
+
+    let filter = args.filter.unwrap_or_default();
+    for &(name, style, scene) in SCREENSHOTS {
+        if !name.contains(&filter) {
+            continue;
+        }
+
+        apply_style(style);
+        let path = args.out_dir.join(format!("{name}.png"));
+        scene().save(&path)?;
+        println!("Wrote {}", path.display());
+    }
+
+    Ok(())
+}
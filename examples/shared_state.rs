@@ -0,0 +1,278 @@
+// ANCHOR: all
+use gtk::prelude::{BoxExt, ButtonExt, GtkWindowExt, OrientableExt, RangeExt, ScaleExt};
+use relm4::{
+    gtk, Component, ComponentController, ComponentParts, ComponentSender, Controller, RelmApp,
+    RelmWidgetExt, SharedState, SimpleComponent,
+};
+
+// ANCHOR: state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Units {
+    Metric,
+    Imperial,
+}
+
+/// User preferences that affect several parts of the app.
+#[derive(Debug, Clone, PartialEq)]
+struct Preferences {
+    units: Units,
+    font_scale: f64,
+}
+
+// `SharedState` creates the initial value with `Default`
+impl Default for Preferences {
+    fn default() -> Self {
+        Self {
+            units: Units::Metric,
+            font_scale: 1.0,
+        }
+    }
+}
+
+/// The central store. Every component can read it, write it or subscribe to it.
+static PREFERENCES: SharedState<Preferences> = SharedState::new();
+// ANCHOR_END: state
+
+fn scaled(text: &str, preferences: &Preferences) -> String {
+    format!(
+        "<span size=\"{}%\">{text}</span>",
+        (preferences.font_scale * 100.0).round()
+    )
+}
+
+// ANCHOR: editor
+/// Changes the preferences. It subscribes as well, so a reset updates its controls.
+struct Editor {
+    preferences: Preferences,
+}
+
+#[derive(Debug)]
+enum EditorMsg {
+    Changed(Preferences),
+    SetUnits(Units),
+    SetFontScale(f64),
+    Reset,
+}
+
+#[relm4::component]
+impl SimpleComponent for Editor {
+    type Init = ();
+    type Input = EditorMsg;
+    type Output = ();
+
+    view! {
+        gtk::Box {
+            set_orientation: gtk::Orientation::Vertical,
+            set_spacing: 5,
+
+            gtk::DropDown::from_strings(&["Metric", "Imperial"]) {
+                #[watch]
+                set_selected: match model.preferences.units {
+                    Units::Metric => 0,
+                    Units::Imperial => 1,
+                },
+                connect_selected_notify[sender] => move |dropdown| {
+                    let units = if dropdown.selected() == 0 { Units::Metric } else { Units::Imperial };
+                    sender.input(EditorMsg::SetUnits(units));
+                },
+            },
+
+            gtk::Scale::with_range(gtk::Orientation::Horizontal, 0.5, 2.0, 0.1) {
+                set_digits: 1,
+                set_draw_value: true,
+                #[watch]
+                set_value: model.preferences.font_scale,
+                connect_value_changed[sender] => move |scale| {
+                    sender.input(EditorMsg::SetFontScale(scale.value()));
+                },
+            },
+
+            gtk::Button {
+                set_label: "Reset",
+                connect_clicked => EditorMsg::Reset,
+            },
+        }
+    }
+
+    fn init(_: (), root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+        PREFERENCES.subscribe(sender.input_sender(), |preferences| {
+            EditorMsg::Changed(preferences.clone())
+        });
+
+        let model = Editor {
+            preferences: PREFERENCES.read().clone(),
+        };
+        let widgets = view_output!();
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            EditorMsg::Changed(preferences) => self.preferences = preferences,
+            // Writing notifies all subscribers, even if nothing changed.
+            // Comparing first also stops the update loop with the widgets.
+            EditorMsg::SetUnits(units) => {
+                if self.preferences.units != units {
+                    PREFERENCES.write().units = units;
+                }
+            }
+            EditorMsg::SetFontScale(font_scale) => {
+                if self.preferences.font_scale != font_scale {
+                    PREFERENCES.write().font_scale = font_scale;
+                }
+            }
+            EditorMsg::Reset => *PREFERENCES.write() = Preferences::default(),
+        }
+    }
+}
+// ANCHOR_END: editor
+
+// ANCHOR: distance
+/// Shows the length of a marathon in the selected units.
+struct Distance {
+    preferences: Preferences,
+}
+
+#[derive(Debug)]
+enum DistanceMsg {
+    Changed(Preferences),
+}
+
+#[relm4::component]
+impl SimpleComponent for Distance {
+    type Init = ();
+    type Input = DistanceMsg;
+    type Output = ();
+
+    view! {
+        gtk::Label {
+            #[watch]
+            set_markup: &scaled(
+                &match model.preferences.units {
+                    Units::Metric => "Marathon: 42.195 km".to_owned(),
+                    Units::Imperial => format!("Marathon: {:.1} mi", 42.195 / 1.609_344),
+                },
+                &model.preferences,
+            ),
+        }
+    }
+
+    fn init(_: (), root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+        // The subscription sends a message whenever the preferences were written
+        PREFERENCES.subscribe(sender.input_sender(), |preferences| {
+            DistanceMsg::Changed(preferences.clone())
+        });
+
+        let model = Distance {
+            preferences: PREFERENCES.read().clone(),
+        };
+        let widgets = view_output!();
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            DistanceMsg::Changed(preferences) => self.preferences = preferences,
+        }
+    }
+}
+// ANCHOR_END: distance
+
+// ANCHOR: temperature
+/// Shows the boiling point of water in the selected units.
+struct Temperature {
+    preferences: Preferences,
+}
+
+#[derive(Debug)]
+enum TemperatureMsg {
+    Changed(Preferences),
+}
+
+#[relm4::component]
+impl SimpleComponent for Temperature {
+    type Init = ();
+    type Input = TemperatureMsg;
+    type Output = ();
+
+    view! {
+        gtk::Label {
+            #[watch]
+            set_markup: &scaled(
+                match model.preferences.units {
+                    Units::Metric => "Water boils at 100 °C",
+                    Units::Imperial => "Water boils at 212 °F",
+                },
+                &model.preferences,
+            ),
+        }
+    }
+
+    fn init(_: (), root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+        PREFERENCES.subscribe(sender.input_sender(), |preferences| {
+            TemperatureMsg::Changed(preferences.clone())
+        });
+
+        let model = Temperature {
+            preferences: PREFERENCES.read().clone(),
+        };
+        let widgets = view_output!();
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            TemperatureMsg::Changed(preferences) => self.preferences = preferences,
+        }
+    }
+}
+// ANCHOR_END: temperature
+
+// ANCHOR: app
+/// The parent only places the components, it doesn't forward any messages.
+struct App {
+    editor: Controller<Editor>,
+    distance: Controller<Distance>,
+    temperature: Controller<Temperature>,
+}
+
+#[relm4::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = ();
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            set_title: Some("Shared state"),
+            set_default_width: 300,
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+                set_spacing: 10,
+                set_margin_all: 10,
+
+                append: model.editor.widget(),
+                append: model.distance.widget(),
+                append: model.temperature.widget(),
+            },
+        }
+    }
+
+    fn init(_: (), root: Self::Root, _sender: ComponentSender<Self>) -> ComponentParts<Self> {
+        let model = App {
+            editor: Editor::builder().launch(()).detach(),
+            distance: Distance::builder().launch(()).detach(),
+            temperature: Temperature::builder().launch(()).detach(),
+        };
+        let widgets = view_output!();
+        ComponentParts { model, widgets }
+    }
+}
+// ANCHOR_END: app
+
+fn main() {
+    let app = RelmApp::new("relm4.example.shared_state");
+    app.run::<App>(());
+}
+// ANCHOR_END: all
@@ -121,7 +121,7 @@ impl SimpleComponent for AppModel {
     }
     // ANCHOR_END: view
 }
-// ANCHOR_END: app_update
+// ANCHOR_END: simple_component
 
 // ANCHOR: main
 fn main() {
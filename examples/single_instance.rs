@@ -0,0 +1,170 @@
+/* ANCHOR: all */
+use std::path::PathBuf;
+
+use clap::Parser;
+use gtk::prelude::{
+    ApplicationCommandLineExt, ApplicationExt, FileExt, GtkWindowExt, OrientableExt,
+};
+use gtk::{gio, glib};
+use relm4::{
+    gtk, ComponentParts, ComponentSender, MessageBroker, RelmApp, RelmWidgetExt, SimpleComponent,
+};
+
+/* ANCHOR: broker */
+// The command line handlers run outside of the component,
+// so they use the broker to send messages to it.
+static BROKER: MessageBroker<AppMsg> = MessageBroker::new();
+/* ANCHOR_END: broker */
+
+/* ANCHOR: args_struct */
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+struct Args {
+    /// Text to show in the window of the running instance
+    #[arg(long)]
+    message: Option<String>,
+
+    /// Quit the running instance
+    #[arg(long)]
+    quit: bool,
+
+    /// Files to open in the running instance
+    files: Vec<PathBuf>,
+}
+/* ANCHOR_END: args_struct */
+
+#[derive(Debug)]
+enum AppMsg {
+    Message(String),
+    Open(Vec<PathBuf>),
+    Quit,
+}
+
+struct AppModel {
+    entries: Vec<String>,
+}
+
+#[relm4::component]
+impl SimpleComponent for AppModel {
+    type Init = ();
+    type Input = AppMsg;
+    type Output = ();
+
+    view! {
+        gtk::ApplicationWindow {
+            set_title: Some("Single instance"),
+            set_default_width: 300,
+            set_default_height: 200,
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+                set_margin_all: 5,
+
+                gtk::Label {
+                    set_xalign: 0.0,
+                    #[watch]
+                    set_label: &model.entries.join("\n"),
+                }
+            }
+        }
+    }
+
+    fn init(
+        _init: Self::Init,
+        root: Self::Root,
+        _sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = AppModel {
+            entries: Vec::new(),
+        };
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        let entry = match msg {
+            AppMsg::Message(text) => format!("Message: {text}"),
+            AppMsg::Open(files) => {
+                let files: Vec<String> = files
+                    .iter()
+                    .map(|file| file.display().to_string())
+                    .collect();
+                format!("Open: {}", files.join(", "))
+            }
+            AppMsg::Quit => {
+                relm4::main_application().quit();
+                return;
+            }
+        };
+        // Also print the entry so the running instance can be observed from a terminal
+        println!("{entry}");
+        self.entries.push(entry);
+    }
+}
+
+/* ANCHOR: command_line */
+/// Runs in the primary instance, no matter which process was invoked.
+fn handle_command_line(
+    app: &gtk::Application,
+    command_line: &gio::ApplicationCommandLine,
+) -> glib::ExitCode {
+    let Ok(args) = Args::try_parse_from(command_line.arguments()) else {
+        // The invoking process already reported the error
+        return glib::ExitCode::FAILURE;
+    };
+
+    if let Some(text) = args.message {
+        BROKER.send(AppMsg::Message(text));
+    }
+    if !args.files.is_empty() {
+        // Relative paths are resolved against the working
+        // directory of the invoking process
+        let files = args
+            .files
+            .iter()
+            .filter_map(|file| command_line.create_file_for_arg(file).path())
+            .collect();
+        BROKER.send(AppMsg::Open(files));
+    }
+    // The component was launched at startup, so it already received the messages.
+    // Handling the command line replaces the activation, which shows the window.
+    if args.quit {
+        BROKER.send(AppMsg::Quit);
+    } else {
+        app.activate();
+    }
+
+    glib::ExitCode::SUCCESS
+}
+
+/// Runs if another program, e.g. a file manager, asks the app to open files.
+fn handle_open(app: &gtk::Application, files: &[gio::File], _hint: &str) {
+    BROKER.send(AppMsg::Open(
+        files.iter().filter_map(|file| file.path()).collect(),
+    ));
+    app.activate();
+}
+/* ANCHOR_END: command_line */
+
+/* ANCHOR: main */
+fn main() {
+    // Validate the arguments in the invoked process, so errors and
+    // the help text are shown where the user expects them.
+    Args::parse();
+
+    let app = gtk::Application::builder()
+        .application_id("relm4.example.single_instance")
+        .flags(gio::ApplicationFlags::HANDLES_COMMAND_LINE | gio::ApplicationFlags::HANDLES_OPEN)
+        .build();
+    app.connect_command_line(handle_command_line);
+    app.connect_open(handle_open);
+
+    // All arguments are forwarded to the primary instance
+    RelmApp::from_app(app)
+        .with_broker(&BROKER)
+        .with_args(std::env::args().collect())
+        .run::<AppModel>(());
+}
+/* ANCHOR_END: main */
+/* ANCHOR_END: all */
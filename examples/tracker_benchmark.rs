@@ -0,0 +1,503 @@
+// ANCHOR: all
+//! Measures how long `update_view` takes when widgets are updated with
+//! `#[watch]` compared to `#[track]`.
+//!
+//! Both components are generated from the same `view!` by `bench_component!`,
+//! which only differs in the attribute that's put in front of the setters.
+//! `update_view` is timed with `pre_view` and `post_view`, so the measurement
+//! covers exactly the code generated by the component macro.
+//!
+//! Run it with `cargo run --release --example tracker_benchmark`.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+use gtk::prelude::{BoxExt, ButtonExt, GtkWindowExt, OrientableExt, WidgetExt};
+use relm4::{
+    gtk, Component, ComponentController, ComponentParts, ComponentSender, Controller, RelmApp,
+    RelmWidgetExt, SimpleComponent,
+};
+
+/// Number of tracked fields, each one controls a group of widgets.
+const GROUPS: usize = 8;
+/// Number of updates measured per strategy and change pattern.
+const ITERATIONS: usize = 200;
+
+const ICON_LIST: &[&str] = &[
+    "bookmark-new-symbolic",
+    "edit-copy-symbolic",
+    "edit-cut-symbolic",
+    "edit-find-symbolic",
+    "starred-symbolic",
+    "system-run-symbolic",
+    "emoji-objects-symbolic",
+    "emoji-nature-symbolic",
+    "display-brightness-symbolic",
+];
+
+fn icon(value: u32) -> &'static str {
+    ICON_LIST[value as usize % ICON_LIST.len()]
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Strategy {
+    Watch,
+    Track,
+}
+
+// ANCHOR: pattern
+/// How many fields of the model change during a single update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Pattern {
+    /// Only one of the groups changes.
+    Sparse,
+    /// All groups change.
+    Dense,
+}
+// ANCHOR_END: pattern
+
+// ANCHOR: model
+/// The values shown by the view, one per group of widgets.
+#[tracker::track]
+struct BenchValues {
+    g0: u32,
+    g1: u32,
+    g2: u32,
+    g3: u32,
+    g4: u32,
+    g5: u32,
+    g6: u32,
+    g7: u32,
+}
+
+/// The model of both benchmark components.
+/// Only the values are tracked, the other fields configure the benchmark.
+struct BenchModel {
+    values: BenchValues,
+    pattern: Pattern,
+    step: usize,
+    /// The run to report the samples for, if the last message asked for a report.
+    report: Option<u32>,
+}
+// ANCHOR_END: model
+
+impl BenchValues {
+    fn bump(&mut self, group: usize) {
+        let inc = |value: &mut u32| *value = value.wrapping_add(1);
+        match group {
+            0 => self.update_g0(inc),
+            1 => self.update_g1(inc),
+            2 => self.update_g2(inc),
+            3 => self.update_g3(inc),
+            4 => self.update_g4(inc),
+            5 => self.update_g5(inc),
+            6 => self.update_g6(inc),
+            7 => self.update_g7(inc),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl BenchModel {
+    fn new() -> Self {
+        Self {
+            values: BenchValues {
+                g0: 0,
+                g1: 0,
+                g2: 0,
+                g3: 0,
+                g4: 0,
+                g5: 0,
+                g6: 0,
+                g7: 0,
+                tracker: 0,
+            },
+            pattern: Pattern::Sparse,
+            step: 0,
+            report: None,
+        }
+    }
+
+    fn update(&mut self, msg: BenchMsg) {
+        self.values.reset();
+        self.report = None;
+
+        match msg {
+            BenchMsg::Step(pattern) => {
+                self.pattern = pattern;
+                match pattern {
+                    Pattern::Sparse => self.values.bump(self.step % GROUPS),
+                    Pattern::Dense => (0..GROUPS).for_each(|group| self.values.bump(group)),
+                }
+                self.step += 1;
+            }
+            BenchMsg::Report(run) => self.report = Some(run),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum BenchMsg {
+    Step(Pattern),
+    /// Sends the samples since the last report, tagged with the number of the run.
+    Report(u32),
+}
+
+#[derive(Debug)]
+struct BenchOutput {
+    run: u32,
+    strategy: Strategy,
+    pattern: Pattern,
+    samples: Vec<Duration>,
+}
+
+/// Defines a benchmark component whose widgets are updated with the given
+/// attribute, e.g. `g0: #[watch]` for all widgets of the first group.
+///
+/// The name of the model is passed in as well, because the conditions
+/// of `#[track]` are written outside of the macro and couldn't see it otherwise.
+macro_rules! bench_component {
+    ($name:ident, $model:ident, $strategy:expr, { $($group:ident: #[$($attr:tt)*]),* $(,)? }) => {
+        struct $name(BenchModel);
+
+        #[relm4::component]
+        impl SimpleComponent for $name {
+            type Init = ();
+            type Input = BenchMsg;
+            type Output = BenchOutput;
+
+            view! {
+                gtk::Box {
+                    set_orientation: gtk::Orientation::Vertical,
+                    set_spacing: 5,
+
+                    gtk::Label {
+                        set_label: &format!("{:?}", $strategy),
+                    },
+
+                    $(
+                        // Ten labels and five images per group
+                        gtk::Box {
+                            set_orientation: gtk::Orientation::Horizontal,
+                            set_spacing: 5,
+
+                            gtk::Label {
+                                #[$($attr)*]
+                                set_label: &$model.0.values.$group.to_string(),
+                            },
+
+                            gtk::Label {
+                                #[$($attr)*]
+                                set_label: &$model.0.values.$group.to_string(),
+                            },
+
+                            gtk::Label {
+                                #[$($attr)*]
+                                set_label: &$model.0.values.$group.to_string(),
+                            },
+
+                            gtk::Label {
+                                #[$($attr)*]
+                                set_label: &$model.0.values.$group.to_string(),
+                            },
+
+                            gtk::Label {
+                                #[$($attr)*]
+                                set_label: &$model.0.values.$group.to_string(),
+                            },
+
+                            gtk::Label {
+                                #[$($attr)*]
+                                set_label: &$model.0.values.$group.to_string(),
+                            },
+
+                            gtk::Label {
+                                #[$($attr)*]
+                                set_label: &$model.0.values.$group.to_string(),
+                            },
+
+                            gtk::Label {
+                                #[$($attr)*]
+                                set_label: &$model.0.values.$group.to_string(),
+                            },
+
+                            gtk::Label {
+                                #[$($attr)*]
+                                set_label: &$model.0.values.$group.to_string(),
+                            },
+
+                            gtk::Label {
+                                #[$($attr)*]
+                                set_label: &$model.0.values.$group.to_string(),
+                            },
+
+                            gtk::Image {
+                                #[$($attr)*]
+                                set_icon_name: Some(icon($model.0.values.$group)),
+                            },
+
+                            gtk::Image {
+                                #[$($attr)*]
+                                set_icon_name: Some(icon($model.0.values.$group)),
+                            },
+
+                            gtk::Image {
+                                #[$($attr)*]
+                                set_icon_name: Some(icon($model.0.values.$group)),
+                            },
+
+                            gtk::Image {
+                                #[$($attr)*]
+                                set_icon_name: Some(icon($model.0.values.$group)),
+                            },
+
+                            gtk::Image {
+                                #[$($attr)*]
+                                set_icon_name: Some(icon($model.0.values.$group)),
+                            },
+                        },
+                    )*
+                }
+            }
+
+            additional_fields! {
+                samples: BTreeMap<Pattern, Vec<Duration>>,
+            }
+
+            fn init(
+                _: Self::Init,
+                root: Self::Root,
+                _sender: ComponentSender<Self>,
+            ) -> ComponentParts<Self> {
+                let $model = $name(BenchModel::new());
+                let samples = BTreeMap::new();
+                let widgets = view_output!();
+
+                ComponentParts {
+                    model: $model,
+                    widgets,
+                }
+            }
+
+            fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+                self.0.update(msg);
+            }
+
+            // ANCHOR: timing
+            // Only the setters are measured here, layout and rendering
+            // happen later on the frame clock and cost the same for both.
+            fn pre_view() {
+                let start = Instant::now();
+            }
+
+            fn post_view() {
+                let elapsed = start.elapsed();
+
+                if let Some(run) = $model.0.report {
+                    for (pattern, samples) in std::mem::take(samples) {
+                        sender
+                            .output(BenchOutput {
+                                run,
+                                strategy: $strategy,
+                                pattern,
+                                samples,
+                            })
+                            .unwrap();
+                    }
+                    return;
+                }
+
+                samples.entry($model.0.pattern).or_default().push(elapsed);
+            }
+            // ANCHOR_END: timing
+        }
+    };
+}
+
+// ANCHOR: variants
+bench_component!(WatchBench, model, Strategy::Watch, {
+    g0: #[watch],
+    g1: #[watch],
+    g2: #[watch],
+    g3: #[watch],
+    g4: #[watch],
+    g5: #[watch],
+    g6: #[watch],
+    g7: #[watch],
+});
+
+bench_component!(TrackBench, model, Strategy::Track, {
+    g0: #[track = "model.0.values.changed(BenchValues::g0())"],
+    g1: #[track = "model.0.values.changed(BenchValues::g1())"],
+    g2: #[track = "model.0.values.changed(BenchValues::g2())"],
+    g3: #[track = "model.0.values.changed(BenchValues::g3())"],
+    g4: #[track = "model.0.values.changed(BenchValues::g4())"],
+    g5: #[track = "model.0.values.changed(BenchValues::g5())"],
+    g6: #[track = "model.0.values.changed(BenchValues::g6())"],
+    g7: #[track = "model.0.values.changed(BenchValues::g7())"],
+});
+// ANCHOR_END: variants
+
+// ANCHOR: stats
+struct Stats {
+    mean: Duration,
+    median: Duration,
+    max: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        let total: Duration = samples.iter().sum();
+        Self {
+            mean: total / samples.len() as u32,
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+fn format_table(results: &[BenchOutput]) -> String {
+    let micros = |duration: Duration| duration.as_secs_f64() * 1_000_000.0;
+
+    let mut table = String::from(
+        "| strategy | changes | mean (µs) | median (µs) | max (µs) |\n\
+         |----------|---------|-----------|-------------|----------|\n",
+    );
+    for result in results {
+        let stats = Stats::new(result.samples.clone());
+        writeln!(
+            table,
+            "| {:<8} | {:<7} | {:>9.1} | {:>11.1} | {:>8.1} |",
+            format!("{:?}", result.strategy),
+            format!("{:?}", result.pattern),
+            micros(stats.mean),
+            micros(stats.median),
+            micros(stats.max),
+        )
+        .unwrap();
+    }
+    table
+}
+// ANCHOR_END: stats
+
+struct App {
+    watch: Controller<WatchBench>,
+    track: Controller<TrackBench>,
+    /// Incremented on every click, so results of older runs can be ignored.
+    run: u32,
+    results: Vec<BenchOutput>,
+    table: String,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    Run,
+    Result(BenchOutput),
+}
+
+#[relm4::component]
+impl SimpleComponent for App {
+    type Init = ();
+    type Input = AppMsg;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            set_title: Some("Watch vs track benchmark"),
+            set_default_size: (1000, 700),
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+                set_spacing: 5,
+                set_margin_all: 5,
+
+                gtk::Button {
+                    set_label: "Run benchmark",
+                    connect_clicked => AppMsg::Run,
+                },
+
+                gtk::Label {
+                    add_css_class: "monospace",
+                    set_xalign: 0.0,
+                    #[watch]
+                    set_label: &model.table,
+                },
+
+                gtk::Box {
+                    set_orientation: gtk::Orientation::Horizontal,
+                    set_spacing: 5,
+                    set_homogeneous: true,
+                    set_vexpand: true,
+
+                    gtk::ScrolledWindow {
+                        set_child: Some(model.watch.widget()),
+                    },
+
+                    gtk::ScrolledWindow {
+                        set_child: Some(model.track.widget()),
+                    },
+                },
+            }
+        }
+    }
+
+    fn init(_: (), root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+        let model = App {
+            watch: WatchBench::builder()
+                .launch(())
+                .forward(sender.input_sender(), AppMsg::Result),
+            track: TrackBench::builder()
+                .launch(())
+                .forward(sender.input_sender(), AppMsg::Result),
+            run: 0,
+            results: Vec::new(),
+            table: String::from("Press the button to start"),
+        };
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    // ANCHOR: run
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            AppMsg::Run => {
+                self.run += 1;
+                self.results.clear();
+                self.table = String::from("Running…");
+
+                // Messages are processed in order, so the report
+                // is sent after all measured updates are done.
+                for bench in [self.watch.sender(), self.track.sender()] {
+                    for pattern in [Pattern::Sparse, Pattern::Dense] {
+                        for _ in 0..ITERATIONS {
+                            bench.emit(BenchMsg::Step(pattern));
+                        }
+                    }
+                    bench.emit(BenchMsg::Report(self.run));
+                }
+            }
+            // A run that was still going when the button was pressed again
+            AppMsg::Result(result) if result.run != self.run => (),
+            AppMsg::Result(result) => {
+                self.results.push(result);
+                // Two strategies, two patterns each
+                if self.results.len() == 4 {
+                    self.results
+                        .sort_by_key(|result| (result.pattern, result.strategy as u8));
+                    self.table = format_table(&self.results);
+                }
+            }
+        }
+    }
+    // ANCHOR_END: run
+}
+
+fn main() {
+    let app = RelmApp::new("relm4.example.tracker_benchmark");
+    app.run::<App>(());
+}
+// ANCHOR_END: all
@@ -0,0 +1,183 @@
+// ANCHOR: all
+use gtk::prelude::{BoxExt, ButtonExt, GtkWindowExt, OrientableExt};
+use relm4::{gtk, ComponentParts, ComponentSender, RelmApp, RelmWidgetExt, SimpleComponent};
+
+// ANCHOR: model
+// A tracked struct can be used as a field of another tracked struct.
+// Both of them have their own tracker value.
+#[tracker::track]
+struct Settings {
+    compact: bool,
+    accent: bool,
+}
+
+#[tracker::track]
+struct AppModel {
+    // `Settings` doesn't implement `PartialEq`, so `set_settings()`
+    // can't compare the values and always marks the field as changed.
+    #[no_eq]
+    settings: Settings,
+    items: Vec<String>,
+    // Not shown in the UI, so there's no need to track it.
+    #[do_not_track]
+    next_item: u32,
+}
+// ANCHOR_END: model
+
+#[derive(Debug)]
+enum AppInput {
+    ToggleCompact,
+    ToggleAccent,
+    AddItem,
+    RemoveItem,
+    ClearItems,
+}
+
+#[relm4::component]
+impl SimpleComponent for AppModel {
+    type Init = ();
+    type Input = AppInput;
+    type Output = ();
+
+    // ANCHOR: view
+    view! {
+        gtk::Window {
+            set_title: Some("Nested tracker"),
+            set_default_size: (300, 300),
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+                set_margin_all: 10,
+                // Checks the nested tracker only
+                #[track = "model.settings.changed(Settings::compact())"]
+                set_spacing: if model.settings.compact { 2 } else { 12 },
+                #[track = "model.settings.changed(Settings::accent())"]
+                set_class_active: ("accent", model.settings.accent),
+
+                gtk::Label {
+                    // Checks the outer tracker which marks `settings` as changed
+                    // whenever it's accessed with `get_mut_settings()`
+                    #[track = "model.changed(AppModel::settings())"]
+                    set_label: &format!(
+                        "Compact: {}, accent: {}",
+                        model.settings.compact, model.settings.accent
+                    ),
+                },
+
+                gtk::Button {
+                    set_label: "Toggle compact",
+                    connect_clicked => AppInput::ToggleCompact,
+                },
+
+                gtk::Button {
+                    set_label: "Toggle accent",
+                    connect_clicked => AppInput::ToggleAccent,
+                },
+
+                gtk::Box {
+                    set_orientation: gtk::Orientation::Horizontal,
+                    set_spacing: 5,
+                    set_homogeneous: true,
+
+                    gtk::Button {
+                        set_label: "Add",
+                        connect_clicked => AppInput::AddItem,
+                    },
+
+                    gtk::Button {
+                        set_label: "Remove",
+                        connect_clicked => AppInput::RemoveItem,
+                    },
+
+                    gtk::Button {
+                        set_label: "Clear",
+                        connect_clicked => AppInput::ClearItems,
+                    },
+                },
+
+                gtk::Label {
+                    // The whole `Vec` is tracked as one field.
+                    // For larger collections, use a factory instead.
+                    #[track = "model.changed(AppModel::items())"]
+                    set_label: &if model.items.is_empty() {
+                        String::from("No items")
+                    } else {
+                        model.items.join("\n")
+                    },
+                },
+            }
+        }
+    }
+    // ANCHOR_END: view
+
+    fn init(
+        _params: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = AppModel {
+            settings: Settings {
+                compact: false,
+                accent: false,
+                tracker: 0,
+            },
+            items: Vec::new(),
+            next_item: 1,
+            tracker: 0,
+        };
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    // ANCHOR: update
+    fn update(&mut self, message: Self::Input, _sender: ComponentSender<Self>) {
+        // `reset()` only resets the tracker of the outer struct.
+        // Without resetting the nested one, its changes would
+        // stay marked and trigger updates forever.
+        self.reset();
+        self.settings.reset();
+
+        match message {
+            AppInput::ToggleCompact => {
+                // `get_mut_settings()` marks `settings` as changed in the outer
+                // tracker and the setter marks `compact` in the nested one.
+                // Calling `self.settings.set_compact()` directly would only
+                // update the nested tracker and the label above would miss it.
+                let settings = self.get_mut_settings();
+                settings.set_compact(!settings.compact);
+            }
+            AppInput::ToggleAccent => {
+                let settings = self.get_mut_settings();
+                settings.set_accent(!settings.accent);
+            }
+            AppInput::AddItem => {
+                // `get_mut_items()` always marks the field as changed,
+                // which is what we want after modifying the collection.
+                let item = format!("Item {}", self.next_item);
+                self.next_item += 1;
+                self.get_mut_items().push(item);
+            }
+            AppInput::RemoveItem => {
+                // Only mark the field as changed if there was something to remove.
+                if !self.items.is_empty() {
+                    self.get_mut_items().pop();
+                }
+            }
+            AppInput::ClearItems => {
+                // The setter compares the values, so clearing
+                // an empty list doesn't mark the field as changed.
+                self.set_items(Vec::new());
+            }
+        }
+    }
+    // ANCHOR_END: update
+}
+
+fn main() {
+    let app = RelmApp::new("relm4.example.tracker_nested");
+    relm4::set_global_css(".accent { background: #3584e4; color: white; }");
+    app.run::<AppModel>(());
+}
+// ANCHOR_END: all
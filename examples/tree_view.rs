@@ -0,0 +1,290 @@
+// ANCHOR: all
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashMap};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use gtk::prelude::*;
+use gtk::{gio, glib};
+use relm4::{gtk, Component, ComponentParts, ComponentSender, RelmApp, RelmWidgetExt};
+
+// ANCHOR: helpers
+/// A file or directory, read on a background thread.
+#[derive(Debug)]
+struct Entry {
+    path: PathBuf,
+    is_dir: bool,
+}
+
+impl Entry {
+    /// Creates the item of a row, which knows whether the entry is a directory
+    /// without asking the file system again.
+    fn to_item(&self) -> gio::FileInfo {
+        let info = gio::FileInfo::new();
+        info.set_name(&self.path);
+        info.set_file_type(if self.is_dir {
+            gio::FileType::Directory
+        } else {
+            gio::FileType::Regular
+        });
+        info
+    }
+}
+
+fn row_path(row: &gtk::TreeListRow) -> Option<PathBuf> {
+    row.item()
+        .and_downcast::<gio::FileInfo>()
+        .map(|info| info.name())
+}
+
+fn display_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+/// Lists a directory with sub-directories first, skipping hidden files.
+fn read_children(path: &Path) -> io::Result<Vec<Entry>> {
+    let mut children: Vec<Entry> = std::fs::read_dir(path)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| !display_name(path).starts_with('.'))
+        .map(|path| Entry {
+            is_dir: path.is_dir(),
+            path,
+        })
+        .collect();
+    children.sort_by_key(|entry| (!entry.is_dir, display_name(&entry.path).to_lowercase()));
+    Ok(children)
+}
+// ANCHOR_END: helpers
+
+// ANCHOR: model
+struct App {
+    /// Directories the user has expanded. Collapsing a directory keeps
+    /// the entries below it, so its sub-directories open again with it.
+    expanded: BTreeSet<PathBuf>,
+    /// Directories whose children are being loaded, with the row that receives them.
+    loading: HashMap<PathBuf, gtk::TreeListRow>,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    /// A row was expanded or collapsed.
+    RowExpanded(gtk::TreeListRow),
+}
+
+#[derive(Debug)]
+enum CommandMsg {
+    Loaded(PathBuf, io::Result<Vec<Entry>>),
+}
+// ANCHOR_END: model
+
+// ANCHOR: load
+impl App {
+    /// Starts loading the children of a row unless they are loaded already.
+    fn load_children(&mut self, row: gtk::TreeListRow, sender: &ComponentSender<Self>) {
+        let Some(path) = row_path(&row) else {
+            return;
+        };
+        // The list is created by the tree model each time the row is expanded
+        let Some(store) = row.children().and_downcast::<gio::ListStore>() else {
+            return;
+        };
+        if store.n_items() > 0 {
+            return;
+        }
+
+        // A row that is collapsed and expanded again while its children are loading
+        // gets a new list. Only the newest row is kept, and the running command fills it.
+        if self.loading.insert(path.clone(), row).is_some() {
+            return;
+        }
+        // Reading a directory blocks, so it runs on a background thread
+        sender.spawn_oneshot_command(move || {
+            let children = read_children(&path);
+            CommandMsg::Loaded(path, children)
+        });
+    }
+}
+// ANCHOR_END: load
+
+#[relm4::component]
+impl Component for App {
+    type Init = PathBuf;
+    type Input = AppMsg;
+    type Output = ();
+    type CommandOutput = CommandMsg;
+
+    view! {
+        gtk::Window {
+            set_title: Some("Tree view"),
+            set_default_size: (400, 600),
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+
+                gtk::ScrolledWindow {
+                    set_vexpand: true,
+
+                    #[local_ref]
+                    list_view -> gtk::ListView {},
+                },
+
+                gtk::Label {
+                    set_margin_all: 5,
+                    set_xalign: 0.0,
+                    #[watch]
+                    set_label: &if model.loading.is_empty() {
+                        format!("{} directories expanded", model.expanded.len())
+                    } else {
+                        format!("Loading {} directories…", model.loading.len())
+                    },
+                },
+            },
+        }
+    }
+
+    // ANCHOR: init
+    fn init(
+        root_path: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = App {
+            expanded: BTreeSet::new(),
+            loading: HashMap::new(),
+        };
+
+        // The children of directories start empty and are filled by a command.
+        // Files return no model, so they don't get an expander arrow.
+        let root_store = gio::ListStore::new::<gio::FileInfo>();
+        let root_entry = Entry {
+            is_dir: root_path.is_dir(),
+            path: root_path,
+        };
+        root_store.append(&root_entry.to_item());
+        let tree_model = gtk::TreeListModel::new(root_store, false, false, |item| {
+            let info = item.downcast_ref::<gio::FileInfo>()?;
+            (info.file_type() == gio::FileType::Directory)
+                .then(|| gio::ListStore::new::<gio::FileInfo>().upcast())
+        });
+
+        let factory = gtk::SignalListItemFactory::new();
+        let factory_sender = sender.clone();
+        factory.connect_setup(move |_, list_item| {
+            let list_item = list_item.downcast_ref::<gtk::ListItem>().unwrap();
+            let label = gtk::Label::builder().xalign(0.0).build();
+            let expander = gtk::TreeExpander::builder().child(&label).build();
+            list_item.set_child(Some(&expander));
+
+            // List items are reused while scrolling, so the handler
+            // is moved to each new row that's shown by this item.
+            let handler: RefCell<Option<(gtk::TreeListRow, glib::SignalHandlerId)>> =
+                RefCell::default();
+            let sender = factory_sender.clone();
+            list_item.connect_item_notify(move |list_item| {
+                if let Some((row, handler_id)) = handler.take() {
+                    row.disconnect(handler_id);
+                }
+
+                let row = list_item.item().and_downcast::<gtk::TreeListRow>();
+                expander.set_list_row(row.as_ref());
+                let Some(row) = row else {
+                    return;
+                };
+
+                label.set_label(&row_path(&row).as_deref().map(display_name).unwrap_or_default());
+                let handler_id = row.connect_expanded_notify({
+                    let sender = sender.clone();
+                    move |row| sender.input(AppMsg::RowExpanded(row.clone()))
+                });
+                handler.replace(Some((row, handler_id)));
+            });
+        });
+
+        let list_view = &gtk::ListView::new(
+            Some(gtk::SingleSelection::new(Some(tree_model.clone()))),
+            Some(factory),
+        );
+        let widgets = view_output!();
+
+        // Rows expanded by code don't have a handler yet, so the message is sent manually
+        if let Some(row) = tree_model.row(0) {
+            row.set_expanded(true);
+            sender.input(AppMsg::RowExpanded(row));
+        }
+
+        ComponentParts { model, widgets }
+    }
+    // ANCHOR_END: init
+
+    // ANCHOR: update
+    fn update(&mut self, msg: Self::Input, sender: ComponentSender<Self>, _root: &Self::Root) {
+        match msg {
+            AppMsg::RowExpanded(row) => {
+                let Some(path) = row_path(&row) else {
+                    return;
+                };
+                if row.is_expanded() {
+                    self.expanded.insert(path);
+                    self.load_children(row, &sender);
+                } else {
+                    self.expanded.remove(&path);
+                }
+            }
+        }
+    }
+
+    fn update_cmd(
+        &mut self,
+        msg: Self::CommandOutput,
+        sender: ComponentSender<Self>,
+        _root: &Self::Root,
+    ) {
+        match msg {
+            CommandMsg::Loaded(path, result) => {
+                let Some(row) = self.loading.remove(&path) else {
+                    return;
+                };
+                let children = match result {
+                    Ok(children) => children,
+                    Err(err) => {
+                        eprintln!("Could not read {}: {err}", path.display());
+                        return;
+                    }
+                };
+
+                // The row could have been collapsed in the meantime
+                let Some(store) = row.children().and_downcast::<gio::ListStore>() else {
+                    return;
+                };
+                let items: Vec<gio::FileInfo> = children.iter().map(Entry::to_item).collect();
+                store.splice(0, 0, &items);
+
+                // Restore sub-directories that were expanded before
+                for (position, child) in children.iter().enumerate() {
+                    if self.expanded.contains(&child.path)
+                        && let Some(child_row) = row.child_row(position as u32)
+                    {
+                        child_row.set_expanded(true);
+                        self.load_children(child_row, &sender);
+                    }
+                }
+            }
+        }
+    }
+    // ANCHOR_END: update
+}
+
+fn main() {
+    let root = std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| std::env::current_dir().unwrap());
+
+    // Otherwise, GTK would try to open the directory as a file
+    let app = RelmApp::new("relm4.example.tree_view").with_args(Vec::new());
+    app.run::<App>(root);
+}
+// ANCHOR_END: all
@@ -1,5 +1,7 @@
 // ANCHOR: all
-use gtk::prelude::{BoxExt, ButtonExt, GtkWindowExt, OrientableExt};
+use std::path::PathBuf;
+
+use gtk::prelude::{BoxExt, ButtonExt, GtkWindowExt, OrientableExt, WidgetExt};
 use relm4::{gtk, ComponentParts, ComponentSender, RelmApp, RelmWidgetExt, SimpleComponent, WidgetTemplate};
 
 // ANCHOR: home_page_template
@@ -31,10 +33,15 @@ impl WidgetTemplate for SettingsPage {
             set_spacing: 3,
 
             #[name = "btn_dark_mode"]
+            gtk::Button {
+                set_icon_name: "night-light-symbolic",
+            },
+
+            #[name = "btn_go_about"]
             gtk::Button {
                 #[wrap(Some)]
                 set_child = &gtk::Image {
-                    set_icon_name: Some("night-light-symbolic"),
+                    set_icon_name: Some("help-about-symbolic"),
                 },
             },
 
@@ -50,6 +57,30 @@ impl WidgetTemplate for SettingsPage {
 }
 // ANCHOR_END: settings_page_template
 
+// ANCHOR: about_page_template
+#[relm4::widget_template]
+impl WidgetTemplate for AboutPage {
+    view! {
+        gtk::Box {
+            set_orientation: gtk::Orientation::Vertical,
+            set_spacing: 3,
+
+            gtk::Label {
+                set_label: "Nested widget template example",
+            },
+
+            #[name = "btn_go_homepage"]
+            gtk::Button {
+                #[wrap(Some)]
+                set_child = &gtk::Image {
+                    set_icon_name: Some("user-home-symbolic"),
+                },
+            },
+        }
+    }
+}
+// ANCHOR_END: about_page_template
+
 // ANCHOR: main_window_template
 #[relm4::widget_template]
 impl WidgetTemplate for MainWindow {
@@ -59,23 +90,38 @@ impl WidgetTemplate for MainWindow {
             set_default_width: 300,
             set_default_height: 100,
 
+            #[wrap(Some)]
+            set_titlebar = &gtk::HeaderBar {
+                #[name = "btn_back"]
+                pack_start = &gtk::Button {
+                    set_icon_name: "go-previous-symbolic",
+                },
+            },
+
             gtk::Box {
                 set_orientation: gtk::Orientation::Vertical,
 
                 #[name(stk_pages)]
                 gtk::Stack {
                     set_margin_all: 7,
+                    set_transition_type: gtk::StackTransitionType::SlideLeftRight,
 
                     #[template]
                     #[name = "home_page"]
                     add_child = &HomePage {} -> {
-                        set_name: "main",
+                        set_name: Page::Home.name(),
                     },
 
                     #[template]
                     #[name = "settings_page"]
                     add_child = &SettingsPage {} -> {
-                        set_name: "settings",
+                        set_name: Page::Settings.name(),
+                    },
+
+                    #[template]
+                    #[name = "about_page"]
+                    add_child = &AboutPage {} -> {
+                        set_name: Page::About.name(),
                     },
                 },
 
@@ -85,16 +131,75 @@ impl WidgetTemplate for MainWindow {
 }
 // ANCHOR_END: main_window_template
 
+// ANCHOR: page
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Page {
+    Home,
+    Settings,
+    About,
+}
+
+impl Page {
+    fn name(self) -> &'static str {
+        match self {
+            Page::Home => "main",
+            Page::Settings => "settings",
+            Page::About => "about",
+        }
+    }
+}
+// ANCHOR_END: page
+
+// ANCHOR: dark_mode
+/// Applies the dark mode preference to all windows of the app.
+///
+/// With libadwaita, use `adw::StyleManager::set_color_scheme` instead,
+/// because libadwaita ignores this setting.
+fn apply_dark_mode(dark_mode: bool) {
+    if let Some(settings) = gtk::Settings::default() {
+        settings.set_gtk_application_prefer_dark_theme(dark_mode);
+    }
+}
+
+fn dark_mode_file() -> PathBuf {
+    gtk::glib::user_config_dir()
+        .join("relm4-widget-template-nested-access")
+        .join("dark-mode")
+}
+
+fn load_dark_mode() -> bool {
+    std::fs::read_to_string(dark_mode_file()).is_ok_and(|content| content.trim() == "true")
+}
+
+fn save_dark_mode(dark_mode: bool) {
+    let path = dark_mode_file();
+    let result = std::fs::create_dir_all(path.parent().unwrap())
+        .and_then(|()| std::fs::write(&path, dark_mode.to_string()));
+
+    if let Err(err) = result {
+        eprintln!("Could not save dark mode setting: {err}");
+    }
+}
+// ANCHOR_END: dark_mode
+
 // ANCHOR: component_start
-#[derive(Default)]
 struct AppModel {
-    current_page: &'static str,
+    /// Navigation stack, the last page is visible.
+    pages: Vec<Page>,
+    dark_mode: bool,
+}
+
+impl AppModel {
+    fn current_page(&self) -> Page {
+        *self.pages.last().unwrap()
+    }
 }
 
 #[derive(Debug)]
 enum Message {
-    PageHome,
-    PageSettings,
+    Navigate(Page),
+    Back,
+    Home,
     DarkMode,
 }
 
@@ -109,25 +214,48 @@ impl SimpleComponent for AppModel {
         #[template]
         MainWindow {
 
+            #[template_child]
+            btn_back {
+                #[watch]
+                set_sensitive: model.pages.len() > 1,
+                connect_clicked => Message::Back
+            },
+
             #[template_child]
             settings_page.btn_dark_mode {
+                #[watch]
+                set_icon_name: if model.dark_mode {
+                    "weather-clear-symbolic"
+                } else {
+                    "night-light-symbolic"
+                },
                 connect_clicked => Message::DarkMode
             },
 
+            #[template_child]
+            settings_page.btn_go_about {
+                connect_clicked => Message::Navigate(Page::About)
+            },
+
             #[template_child]
             settings_page.btn_go_homepage {
-                connect_clicked => Message::PageHome
+                connect_clicked => Message::Home
+            },
+
+            #[template_child]
+            about_page.btn_go_homepage {
+                connect_clicked => Message::Home
             },
 
             #[template_child]
             home_page.btn_go_settings {
-                connect_clicked => Message::PageSettings
+                connect_clicked => Message::Navigate(Page::Settings)
             },
 
             #[template_child]
             stk_pages {
                 #[watch]
-                set_visible_child_name: model.current_page,
+                set_visible_child_name: model.current_page().name(),
             }
         },
     }
@@ -138,8 +266,12 @@ impl SimpleComponent for AppModel {
         root: Self::Root,
         sender: ComponentSender<Self>,
     ) -> ComponentParts<Self> {
+        let dark_mode = load_dark_mode();
+        apply_dark_mode(dark_mode);
+
         let model = Self {
-            current_page: "main",
+            pages: vec![Page::Home],
+            dark_mode,
         };
 
         let widgets = view_output!();
@@ -147,19 +279,31 @@ impl SimpleComponent for AppModel {
         ComponentParts { model, widgets }
     }
 
+    // ANCHOR: update
     fn update(&mut self, msg: Message, _sender: ComponentSender<Self>) {
         match msg {
             Message::DarkMode => {
-                println!("Mode changed");
+                self.dark_mode = !self.dark_mode;
+                apply_dark_mode(self.dark_mode);
+                save_dark_mode(self.dark_mode);
+            }
+            Message::Navigate(page) => {
+                if self.current_page() != page {
+                    self.pages.push(page);
+                }
             }
-            Message::PageHome => {
-                self.current_page = "main";
+            Message::Back => {
+                // The home page always stays at the bottom of the stack
+                if self.pages.len() > 1 {
+                    self.pages.pop();
+                }
             }
-            Message::PageSettings => {
-                self.current_page = "settings";
+            Message::Home => {
+                self.pages.truncate(1);
             }
         }
     }
+    // ANCHOR_END: update
 }
 
 fn main() {
@@ -0,0 +1,208 @@
+// ANCHOR: all
+use gtk::prelude::{BoxExt, ButtonExt, GtkWindowExt, OrientableExt, WidgetExt};
+use relm4::{
+    gtk, ComponentParts, ComponentSender, RelmApp, RelmWidgetExt, SimpleComponent, WidgetTemplate,
+};
+
+// ANCHOR: border_color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BorderColor {
+    Blue,
+    Red,
+    Green,
+}
+
+impl BorderColor {
+    const ALL: [Self; 3] = [Self::Blue, Self::Red, Self::Green];
+
+    fn css_class(self) -> &'static str {
+        match self {
+            Self::Blue => "border-blue",
+            Self::Red => "border-red",
+            Self::Green => "border-green",
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            Self::Blue => Self::Red,
+            Self::Red => Self::Green,
+            Self::Green => Self::Blue,
+        }
+    }
+}
+// ANCHOR_END: border_color
+
+// ANCHOR: box_template
+#[relm4::widget_template]
+impl WidgetTemplate for FramedBox {
+    view! {
+        gtk::Box {
+            set_margin_all: 10,
+            add_css_class: BorderColor::Blue.css_class(),
+        }
+    }
+}
+// ANCHOR_END: box_template
+
+// ANCHOR: box_properties
+impl FramedBox {
+    /// Replaces the border color set by the template or a previous call.
+    fn set_border_color(&self, color: BorderColor) {
+        for other in BorderColor::ALL {
+            self.remove_css_class(other.css_class());
+        }
+        self.add_css_class(color.css_class());
+    }
+}
+// ANCHOR_END: box_properties
+
+// ANCHOR: spinner_template
+#[relm4::widget_template]
+impl WidgetTemplate for MySpinner {
+    view! {
+        gtk::Spinner {
+            set_size_request: (24, 24),
+        }
+    }
+}
+// ANCHOR_END: spinner_template
+
+// ANCHOR: nested_template
+#[relm4::widget_template]
+impl WidgetTemplate for CustomBox {
+    view! {
+        gtk::Box {
+            set_orientation: gtk::Orientation::Vertical,
+            set_margin_all: 5,
+            set_spacing: 5,
+
+            #[template]
+            FramedBox {
+                set_border_color: BorderColor::Blue,
+                set_spacing: 5,
+
+                #[template]
+                #[name = "outer_spinner"]
+                MySpinner {
+                    set_spinning: true,
+                },
+
+                #[template]
+                FramedBox {
+                    set_border_color: BorderColor::Red,
+                    set_spacing: 10,
+
+                    #[template]
+                    MySpinner {
+                        set_spinning: false,
+                    },
+
+                    #[template]
+                    #[name = "inner_box"]
+                    FramedBox {
+                        set_border_color: BorderColor::Green,
+                        set_spacing: 20,
+
+                        #[name = "child_label"]
+                        gtk::Label {
+                            set_label: "This is a test",
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+// ANCHOR_END: nested_template
+
+struct AppModel {
+    color: BorderColor,
+    spinning: bool,
+}
+
+#[derive(Debug)]
+enum AppMsg {
+    NextColor,
+    ToggleSpinner,
+}
+
+// ANCHOR: component_start
+#[relm4::component]
+impl SimpleComponent for AppModel {
+    type Init = ();
+    type Input = AppMsg;
+    type Output = ();
+
+    view! {
+        gtk::Window {
+            set_title: Some("Widget template properties"),
+            set_default_width: 300,
+            set_default_height: 100,
+
+            #[template]
+            CustomBox {
+                gtk::Button {
+                    set_label: "Next color",
+                    connect_clicked => AppMsg::NextColor,
+                },
+                gtk::Button {
+                    set_label: "Toggle spinner",
+                    connect_clicked => AppMsg::ToggleSpinner,
+                },
+
+                #[template_child]
+                inner_box {
+                    #[watch]
+                    set_border_color: model.color,
+                },
+
+                #[template_child]
+                outer_spinner {
+                    #[watch]
+                    set_spinning: model.spinning,
+                },
+
+                #[template_child]
+                child_label {
+                    #[watch]
+                    set_label: &format!("Border color: {:?}", model.color),
+                }
+            },
+        }
+    }
+    // ANCHOR_END: component_start
+
+    fn init(_: (), root: Self::Root, sender: ComponentSender<Self>) -> ComponentParts<Self> {
+        let model = Self {
+            color: BorderColor::Green,
+            spinning: true,
+        };
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: AppMsg, _sender: ComponentSender<Self>) {
+        match msg {
+            AppMsg::NextColor => {
+                self.color = self.color.next();
+            }
+            AppMsg::ToggleSpinner => {
+                self.spinning = !self.spinning;
+            }
+        }
+    }
+}
+
+fn main() {
+    let app = RelmApp::new("relm4.example.widget_template_properties");
+    relm4::set_global_css(
+        ".border-blue { border: 2px solid blue; }
+         .border-red { border: 2px solid red; }
+         .border-green { border: 2px solid green; }",
+    );
+    app.run::<AppModel>(());
+}
+// ANCHOR_END: all
@@ -0,0 +1,394 @@
+//! Finds the anchors in source files and the includes in chapters.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+/// Directories whose files are checked even if no chapter includes them.
+const SOURCE_DIRS: &[&str] = &["examples", "tests"];
+/// Files with other extensions are ignored when searching the source directories.
+const SOURCE_EXTENSIONS: &[&str] = &["rs", "toml"];
+
+/// A problem at a line of a file.
+#[derive(Debug)]
+pub struct Diagnostic {
+    /// Relative to the root of the book.
+    pub file: PathBuf,
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.file.display(), self.line, self.message)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Report {
+    pub errors: Vec<Diagnostic>,
+    /// Anchors that no chapter includes.
+    pub unused: Vec<Diagnostic>,
+}
+
+enum Marker<'a> {
+    Start(&'a str),
+    End(&'a str),
+}
+
+/// Finds an anchor marker in a line, the same way mdBook does.
+fn marker(line: &str) -> Option<Marker<'_>> {
+    let (rest, is_end) = if let Some((_, rest)) = line.split_once("ANCHOR_END:") {
+        (rest, true)
+    } else {
+        (line.split_once("ANCHOR:")?.1, false)
+    };
+
+    let rest = rest.trim_start();
+    let len = rest
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-'))
+        .unwrap_or(rest.len());
+    let name = &rest[..len];
+
+    match (name.is_empty(), is_end) {
+        (true, _) => None,
+        (false, true) => Some(Marker::End(name)),
+        (false, false) => Some(Marker::Start(name)),
+    }
+}
+
+/// Returns the anchors of a file with the line they start at.
+/// Markers without a partner are reported as errors.
+fn anchors(text: &str, file: &Path, errors: &mut Vec<Diagnostic>) -> BTreeMap<String, usize> {
+    let mut anchors = BTreeMap::new();
+    let mut open: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut error = |line, message| {
+        errors.push(Diagnostic {
+            file: file.to_owned(),
+            line,
+            message,
+        })
+    };
+
+    for (index, content) in text.lines().enumerate() {
+        let line = index + 1;
+        match marker(content) {
+            Some(Marker::Start(name)) => {
+                if let Some(start) = open.insert(name, line) {
+                    error(
+                        line,
+                        format!("`ANCHOR: {name}` is already open since line {start}"),
+                    );
+                }
+                // An anchor can have several parts, the first one is reported
+                anchors.entry(name.to_owned()).or_insert(line);
+            }
+            Some(Marker::End(name)) => {
+                let start = open.remove(name);
+                if start.is_none() {
+                    error(
+                        line,
+                        format!("`ANCHOR_END: {name}` has no matching `ANCHOR: {name}`"),
+                    );
+                }
+            }
+            None => (),
+        }
+    }
+
+    for (name, line) in open {
+        error(
+            line,
+            format!("`ANCHOR: {name}` has no matching `ANCHOR_END: {name}`"),
+        );
+    }
+    anchors
+}
+
+/// An `{{#include path:anchor}}` in a chapter.
+struct Include {
+    chapter: PathBuf,
+    line: usize,
+    /// Relative to the root of the book.
+    file: PathBuf,
+    anchor: Option<String>,
+}
+
+/// Finds the includes of a chapter, ignoring escaped ones like `\{{#include file.rs}}`.
+fn includes(text: &str, chapter: &Path) -> Vec<Include> {
+    let dir = chapter.parent().unwrap_or(Path::new(""));
+    let mut includes = Vec::new();
+
+    for (index, line) in text.lines().enumerate() {
+        let mut rest = line;
+        while let Some(start) = rest.find("{{#") {
+            let escaped = rest[..start].ends_with('\\');
+            let link = &rest[start + 3..];
+            let Some(end) = link.find("}}") else {
+                break;
+            };
+            rest = &link[end + 2..];
+
+            let link = &link[..end];
+            let Some(args) = link
+                .strip_prefix("include")
+                .or_else(|| link.strip_prefix("rustdoc_include"))
+            else {
+                continue;
+            };
+            if escaped || !args.starts_with(char::is_whitespace) {
+                continue;
+            }
+
+            let (path, selector) = match args.trim().split_once(':') {
+                Some((path, selector)) => (path, Some(selector)),
+                None => (args.trim(), None),
+            };
+            // Line ranges like `file.rs:10:20` start with a number or a colon
+            let anchor = selector
+                .filter(|selector| {
+                    !selector.is_empty()
+                        && !selector.starts_with(|c: char| c.is_ascii_digit() || c == ':')
+                })
+                .map(str::to_owned);
+
+            includes.push(Include {
+                chapter: chapter.to_owned(),
+                line: index + 1,
+                file: normalize(&dir.join(path)),
+                anchor,
+            });
+        }
+    }
+    includes
+}
+
+/// Removes `.` and `..` from a relative path, so the same file always has the same path.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => (),
+            Component::ParentDir if normalized.file_name().is_some() => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// Returns all files below `dir` relative to `root`, sorted by path.
+fn files(root: &Path, dir: &Path, extensions: &[&str]) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut dirs = vec![dir.to_owned()];
+
+    while let Some(dir) = dirs.pop() {
+        let Ok(entries) = fs::read_dir(root.join(&dir)) else {
+            continue;
+        };
+        for entry in entries {
+            let entry = entry?;
+            let path = dir.join(entry.file_name());
+            let name = entry.file_name();
+            if name.to_string_lossy().starts_with('.') || name == "target" {
+                continue;
+            }
+
+            if entry.file_type()?.is_dir() {
+                dirs.push(path);
+            } else if path
+                .extension()
+                .is_some_and(|ext| extensions.iter().any(|allowed| ext == *allowed))
+            {
+                files.push(path);
+            }
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+/// Checks all includes of the chapters in `src` and all anchors they refer to.
+///
+/// `src` is relative to `root`, the directory of `book.toml`.
+pub fn check(root: &Path, src: &Path) -> io::Result<Report> {
+    let mut report = Report::default();
+
+    let mut all_includes = Vec::new();
+    for chapter in files(root, src, &["md"])? {
+        let text = fs::read_to_string(root.join(&chapter))?;
+        all_includes.extend(includes(&text, &chapter));
+    }
+
+    let mut sources: BTreeSet<PathBuf> = all_includes
+        .iter()
+        .map(|include| include.file.clone())
+        .collect();
+    for dir in SOURCE_DIRS {
+        sources.extend(files(root, Path::new(dir), SOURCE_EXTENSIONS)?);
+    }
+
+    let mut anchors_by_file = BTreeMap::new();
+    for file in sources {
+        // Missing files are reported with the include below
+        if let Ok(text) = fs::read_to_string(root.join(&file)) {
+            let anchors = anchors(&text, &file, &mut report.errors);
+            anchors_by_file.insert(file, anchors);
+        }
+    }
+
+    let mut used = BTreeSet::new();
+    for include in all_includes {
+        let message = match (anchors_by_file.get(&include.file), include.anchor) {
+            (None, _) => format!("`{}` doesn't exist", include.file.display()),
+            (Some(_), None) => continue,
+            (Some(anchors), Some(anchor)) => {
+                if anchors.contains_key(&anchor) {
+                    used.insert((include.file, anchor));
+                    continue;
+                }
+                format!(
+                    "anchor `{anchor}` doesn't exist in `{}`",
+                    include.file.display()
+                )
+            }
+        };
+        report.errors.push(Diagnostic {
+            file: include.chapter,
+            line: include.line,
+            message,
+        });
+    }
+
+    for (file, anchors) in anchors_by_file {
+        for (anchor, line) in anchors {
+            if !used.contains(&(file.clone(), anchor.clone())) {
+                report.unused.push(Diagnostic {
+                    file: file.clone(),
+                    line,
+                    message: format!("anchor `{anchor}` is never included"),
+                });
+            }
+        }
+    }
+
+    for diagnostics in [&mut report.errors, &mut report.unused] {
+        diagnostics.sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn marker_errors(text: &str) -> Vec<(usize, String)> {
+        let mut errors = Vec::new();
+        anchors(text, Path::new("example.rs"), &mut errors);
+        errors
+            .into_iter()
+            .map(|error| (error.line, error.message))
+            .collect()
+    }
+
+    #[test]
+    fn unbalanced_markers_are_reported() {
+        assert_eq!(
+            marker_errors("// ANCHOR: open\nfn main() {}\n// ANCHOR_END: other\n"),
+            [
+                (
+                    3,
+                    "`ANCHOR_END: other` has no matching `ANCHOR: other`".into()
+                ),
+                (
+                    1,
+                    "`ANCHOR: open` has no matching `ANCHOR_END: open`".into()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_anchors_are_allowed() {
+        let text =
+            "// ANCHOR: outer\n// ANCHOR: inner\n// ANCHOR_END: inner\n// ANCHOR_END: outer\n";
+        let mut errors = Vec::new();
+        let anchors = anchors(text, Path::new("example.rs"), &mut errors);
+
+        assert!(errors.is_empty());
+        assert_eq!(
+            anchors.into_iter().collect::<Vec<_>>(),
+            [("inner".into(), 2), ("outer".into(), 1)]
+        );
+
+        // An anchor can't be nested in itself
+        assert_eq!(
+            marker_errors("// ANCHOR: a\n// ANCHOR: a\n// ANCHOR_END: a\n"),
+            [(2, "`ANCHOR: a` is already open since line 1".into())]
+        );
+    }
+
+    #[test]
+    fn line_ranges_are_not_anchors() {
+        let text = "{{#include ../examples/a.rs:10:20}}\n\
+                    {{#include ../examples/a.rs::5}} and {{#rustdoc_include ./b.rs:main}}\n\
+                    \\{{#include escaped.rs:name}}\n\
+                    {{#include ../examples/c.rs}}\n";
+        let includes: Vec<_> = includes(text, Path::new("src/chapter.md"))
+            .into_iter()
+            .map(|include| (include.line, include.file, include.anchor))
+            .collect();
+
+        assert_eq!(
+            includes,
+            [
+                (1, PathBuf::from("examples/a.rs"), None),
+                (2, PathBuf::from("examples/a.rs"), None),
+                (2, PathBuf::from("src/b.rs"), Some("main".into())),
+                (4, PathBuf::from("examples/c.rs"), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_anchors_and_files_are_reported() {
+        let root = std::env::temp_dir().join(format!("mdbook-anchors-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::create_dir_all(root.join("examples")).unwrap();
+        fs::write(
+            root.join("src/chapter.md"),
+            "{{#include ../examples/a.rs:used}}\n\
+             {{#include ../examples/a.rs:missing}}\n\
+             {{#include ../examples/gone.rs:used}}\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("examples/a.rs"),
+            "// ANCHOR: used\n// ANCHOR_END: used\n// ANCHOR: unused\n// ANCHOR_END: unused\n",
+        )
+        .unwrap();
+
+        let report = check(&root, Path::new("src"));
+        fs::remove_dir_all(&root).unwrap();
+        let report = report.unwrap();
+
+        let errors: Vec<String> = report.errors.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            errors,
+            [
+                "src/chapter.md:2: anchor `missing` doesn't exist in `examples/a.rs`",
+                "src/chapter.md:3: `examples/gone.rs` doesn't exist",
+            ]
+        );
+        let unused: Vec<String> = report.unused.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            unused,
+            ["examples/a.rs:3: anchor `unused` is never included"]
+        );
+    }
+}
@@ -0,0 +1,108 @@
+//! An mdBook preprocessor that checks `{{#include file:anchor}}` links.
+//!
+//! It reports includes of missing files or anchors and `ANCHOR`/`ANCHOR_END`
+//! markers without a partner, and it can list anchors that are never included.
+//! The book itself is passed through unchanged.
+
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use serde_json::Value;
+
+mod check;
+
+#[derive(Parser)]
+#[command(about)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Called by mdBook to find out if a renderer is supported
+    Supports { renderer: String },
+    /// Checks a book without building it
+    Check {
+        /// Lists anchors that are never included
+        #[arg(long)]
+        unused: bool,
+
+        /// Directory of the chapters, relative to the root
+        #[arg(long, default_value = "src")]
+        src: PathBuf,
+
+        /// Directory that contains `book.toml`
+        #[arg(default_value = ".")]
+        root: PathBuf,
+    },
+}
+
+/// Prints the report and returns `false` if there were errors.
+fn run(root: &Path, src: &Path, list_unused: bool) -> bool {
+    let report = match check::check(root, src) {
+        Ok(report) => report,
+        Err(err) => {
+            eprintln!("error: Could not read the book: {err}");
+            return false;
+        }
+    };
+
+    for error in &report.errors {
+        eprintln!("error: {error}");
+    }
+    if list_unused {
+        for unused in &report.unused {
+            eprintln!("warning: {unused}");
+        }
+    }
+
+    report.errors.is_empty()
+}
+
+/// Reads the book from mdBook, checks it and writes it back.
+fn preprocess() -> Result<bool, String> {
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|err| err.to_string())?;
+    let input: Value = serde_json::from_str(&input).map_err(|err| err.to_string())?;
+
+    // mdBook sends `[context, book]`
+    let [context, book] = input
+        .as_array()
+        .and_then(|input| <&[Value; 2]>::try_from(input.as_slice()).ok())
+        .ok_or("Unexpected input from mdBook")?;
+    let root = context["root"].as_str().ok_or("Missing book root")?;
+    let config = &context["config"];
+    let src = config["book"]["src"].as_str().unwrap_or("src");
+    let list_unused = config["preprocessor"]["anchors"]["list-unused"]
+        .as_bool()
+        .unwrap_or(false);
+
+    let ok = run(Path::new(root), Path::new(src), list_unused);
+    if ok {
+        serde_json::to_writer(io::stdout(), book).map_err(|err| err.to_string())?;
+    }
+    Ok(ok)
+}
+
+fn main() -> ExitCode {
+    let ok = match Args::parse().command {
+        // Only the source files are read, so every renderer works
+        Some(Command::Supports { .. }) => true,
+        Some(Command::Check { unused, src, root }) => run(&root, &src, unused),
+        None => preprocess().unwrap_or_else(|err| {
+            eprintln!("error: {err}");
+            false
+        }),
+    };
+
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
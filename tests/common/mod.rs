@@ -0,0 +1,178 @@
+//! Helpers for testing components without a user clicking around.
+//!
+//! Components are launched without a `RelmApp` and their windows are never shown.
+//! GTK still needs a display, which can be a headless backend like Broadway.
+
+// Every test crate compiles this module, but not every test uses every helper
+#![allow(dead_code)]
+
+use std::cell::{Ref, RefCell};
+use std::panic;
+use std::rc::Rc;
+use std::sync::{mpsc, OnceLock};
+
+use relm4::gtk::glib;
+use relm4::gtk::prelude::*;
+use relm4::{gtk, Component, ComponentController, Controller};
+
+// ANCHOR: run
+type Job = Box<dyn FnOnce() + Send>;
+
+fn has_display() -> bool {
+    ["DISPLAY", "WAYLAND_DISPLAY", "GDK_BACKEND"]
+        .iter()
+        .any(|var| std::env::var_os(var).is_some())
+}
+
+/// Fails the test if GTK has no display to connect to.
+pub fn require_display() {
+    assert!(
+        has_display(),
+        "No display is available. Start one for the tests, for example with \
+         `gtk4-broadwayd :5 & GDK_BACKEND=broadway BROADWAY_DISPLAY=:5 cargo test`"
+    );
+}
+
+/// Runs a test on the GTK thread.
+///
+/// `cargo test` runs each test on its own thread, but GTK can only be used
+/// from the thread that initialized it. Therefore, all tests are sent to one thread.
+/// Panics are caught there and raised again on the thread of the test.
+pub fn run(test: impl FnOnce() + Send + 'static) {
+    require_display();
+
+    static GTK_THREAD: OnceLock<mpsc::Sender<Job>> = OnceLock::new();
+    let jobs = GTK_THREAD.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        std::thread::spawn(move || {
+            for job in receiver {
+                job();
+            }
+        });
+        sender
+    });
+
+    let (result_sender, result) = mpsc::channel();
+    jobs.send(Box::new(move || {
+        let outcome = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            gtk::init().expect("Could not initialize GTK");
+            test();
+        }));
+        result_sender.send(outcome).unwrap();
+    }))
+    .unwrap();
+
+    if let Err(payload) = result.recv().unwrap() {
+        panic::resume_unwind(payload);
+    }
+}
+
+/// Processes everything that's waiting in the main loop, including
+/// messages, updates of the view and messages those updates caused.
+pub fn pump() {
+    let context = glib::MainContext::default();
+    while context.pending() {
+        context.iteration(false);
+    }
+}
+// ANCHOR_END: run
+
+// ANCHOR: test_app
+/// A launched component together with the output messages it emitted.
+pub struct TestApp<C: Component> {
+    controller: Controller<C>,
+    outputs: Rc<RefCell<Vec<C::Output>>>,
+}
+
+impl<C> TestApp<C>
+where
+    C: Component,
+    C::Root: IsA<gtk::Widget>,
+{
+    pub fn launch(init: C::Init) -> Self {
+        let outputs: Rc<RefCell<Vec<C::Output>>> = Rc::default();
+        let controller = C::builder().launch(init).connect_receiver({
+            let outputs = outputs.clone();
+            move |_, output| outputs.borrow_mut().push(output)
+        });
+        pump();
+
+        Self {
+            controller,
+            outputs,
+        }
+    }
+
+    /// Sends an input message and waits until the view was updated.
+    pub fn send(&self, msg: C::Input) {
+        self.controller.emit(msg);
+        pump();
+    }
+
+    /// Clicks the button with the given label and waits until the view was updated.
+    ///
+    /// If several buttons have the label, `nth` selects one of them in the order they appear.
+    pub fn click(&self, label: &str, nth: usize) {
+        let buttons: Vec<gtk::Button> = find(self.root())
+            .into_iter()
+            .filter(|button: &gtk::Button| button.label().as_deref() == Some(label))
+            .collect();
+        let button = buttons
+            .get(nth)
+            .unwrap_or_else(|| panic!("Found only {} buttons labeled {label:?}", buttons.len()));
+
+        button.emit_clicked();
+        pump();
+    }
+
+    pub fn model(&self) -> Ref<'_, C> {
+        self.controller.model()
+    }
+
+    pub fn root(&self) -> &C::Root {
+        self.controller.widget()
+    }
+
+    /// Returns the output messages that were emitted since the last call.
+    pub fn take_outputs(&self) -> Vec<C::Output> {
+        self.outputs.take()
+    }
+}
+// ANCHOR_END: test_app
+
+// ANCHOR: find
+/// Returns all widgets of type `W` below `root`, in the order they appear.
+pub fn find<W: IsA<gtk::Widget>>(root: &impl IsA<gtk::Widget>) -> Vec<W> {
+    let mut found = Vec::new();
+    let mut stack = vec![root.clone().upcast::<gtk::Widget>()];
+
+    while let Some(widget) = stack.pop() {
+        // Children are pushed in reverse, so the first child is visited first
+        let mut children = Vec::new();
+        let mut child = widget.first_child();
+        while let Some(current) = child {
+            child = current.next_sibling();
+            children.push(current);
+        }
+        stack.extend(children.into_iter().rev());
+
+        if let Ok(widget) = widget.downcast::<W>() {
+            found.push(widget);
+        }
+    }
+    found
+}
+
+/// Returns the text of all labels below `root`, except for those inside buttons.
+pub fn label_texts(root: &impl IsA<gtk::Widget>) -> Vec<String> {
+    find::<gtk::Label>(root)
+        .iter()
+        .filter(|label| {
+            !label
+                .parent()
+                .is_some_and(|parent| parent.is::<gtk::Button>())
+        })
+        .map(|label| label.label().to_string())
+        .collect()
+}
+// ANCHOR_END: find
@@ -0,0 +1,50 @@
+//! Tests the child components of the `components` example through their output messages.
+
+// The example is compiled as part of this test, so its private items are accessible
+include!("../examples/components.rs");
+
+mod common;
+
+use common::TestApp;
+
+// ANCHOR: test
+#[test]
+fn header_reports_selected_mode() {
+    common::run(|| {
+        let header = TestApp::<HeaderModel>::launch(());
+        // The initial mode is set by the parent, so nothing is reported
+        assert!(header.take_outputs().is_empty());
+
+        header.click("Edit", 0);
+        assert!(matches!(header.take_outputs()[..], [HeaderOutput::Edit]));
+
+        // The buttons are grouped, so clicking the active one changes nothing
+        header.click("Edit", 0);
+        assert!(header.take_outputs().is_empty());
+
+        header.click("Export", 0);
+        header.click("View", 0);
+        assert!(matches!(
+            header.take_outputs()[..],
+            [HeaderOutput::Export, HeaderOutput::View]
+        ));
+    });
+}
+// ANCHOR_END: test
+
+#[test]
+fn dialog_asks_parent_to_close() {
+    common::run(|| {
+        let dialog = TestApp::<DialogModel>::launch(true);
+
+        dialog.send(DialogInput::Show);
+        dialog.send(DialogInput::Cancel);
+        assert!(dialog.model().hidden);
+        assert!(dialog.take_outputs().is_empty());
+
+        dialog.send(DialogInput::Show);
+        dialog.send(DialogInput::Accept);
+        assert!(dialog.model().hidden);
+        assert!(matches!(dialog.take_outputs()[..], [DialogOutput::Close]));
+    });
+}
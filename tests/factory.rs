@@ -0,0 +1,66 @@
+//! Tests the `factory` example, including the output messages of the counters.
+
+// The example is compiled as part of this test, so its private items are accessible
+include!("../examples/factory.rs");
+
+mod common;
+
+use common::{label_texts, TestApp};
+
+#[test]
+fn counters_are_added_and_removed() {
+    common::run(|| {
+        let app = TestApp::<App>::launch(0);
+        assert!(label_texts(app.root()).is_empty());
+
+        app.click("Add counter", 0);
+        app.click("Add counter", 0);
+        app.click("Add counter", 0);
+        assert_eq!(app.model().counters.len(), 3);
+        assert_eq!(label_texts(app.root()), ["0", "1", "2"]);
+
+        app.click("Remove counter", 0);
+        assert_eq!(label_texts(app.root()), ["0", "1"]);
+    });
+}
+
+// ANCHOR: test
+#[test]
+fn counters_update_and_move() {
+    common::run(|| {
+        let app = TestApp::<App>::launch(10);
+        for _ in 0..3 {
+            app.send(AppMsg::AddCounter);
+        }
+        assert_eq!(label_texts(app.root()), ["10", "11", "12"]);
+
+        // Handled by the counter itself
+        app.click("+", 1);
+        app.click("-", 2);
+        assert_eq!(label_texts(app.root()), ["10", "12", "11"]);
+
+        // Output messages of the counters, handled by the parent
+        app.click("To Start", 2);
+        assert_eq!(label_texts(app.root()), ["11", "10", "12"]);
+
+        app.click("Down", 0);
+        assert_eq!(label_texts(app.root()), ["10", "11", "12"]);
+
+        app.click("Up", 2);
+        assert_eq!(label_texts(app.root()), ["10", "12", "11"]);
+    });
+}
+// ANCHOR_END: test
+
+#[test]
+fn moving_past_the_ends_does_nothing() {
+    common::run(|| {
+        let app = TestApp::<App>::launch(0);
+        app.send(AppMsg::AddCounter);
+        app.send(AppMsg::AddCounter);
+
+        app.click("Up", 0);
+        app.click("Down", 1);
+        assert_eq!(label_texts(app.root()), ["0", "1"]);
+    });
+}
@@ -0,0 +1,144 @@
+//! Records a session of the `replay` example, replays it in a second app
+//! and checks that both apps show the same counters.
+
+// The example is compiled as part of this test, so its private items are accessible
+include!("../examples/replay.rs");
+
+mod common;
+
+use common::TestApp;
+
+/// A file in the temporary directory that is removed when dropped.
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new(name: &str) -> Self {
+        Self(std::env::temp_dir().join(format!("relm4-{}-{name}", std::process::id())))
+    }
+
+    fn create(&self) -> File {
+        File::create(&self.0).unwrap()
+    }
+
+    fn events(&self) -> Vec<(Duration, Event)> {
+        parse_recording(&std::fs::read_to_string(&self.0).unwrap()).unwrap()
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+/// The counters as the factory shows them.
+fn shown_counters(app: &TestApp<App>) -> Vec<CounterData> {
+    let model = app.model();
+    model
+        .counters
+        .iter()
+        .map(|counter| counter.data.clone())
+        .collect()
+}
+
+// ANCHOR: parse
+#[test]
+fn recordings_are_read_back() {
+    let events = [
+        Event::AddCounter,
+        Event::AddCounter,
+        Event::AddCounter,
+        Event::Increment(1),
+        Event::MoveUp(2),
+        Event::SendFront(2),
+        Event::Decrement(0),
+        Event::MoveDown(0),
+        Event::RemoveCounter,
+        Event::AddCounter,
+    ];
+
+    let mut recorder = Recorder::new(Vec::new());
+    for event in events {
+        recorder.record(event).unwrap();
+    }
+
+    let recording = String::from_utf8(recorder.writer).unwrap();
+    let read_back: Vec<Event> = parse_recording(&recording)
+        .unwrap()
+        .into_iter()
+        .map(|(_, event)| event)
+        .collect();
+    assert_eq!(read_back, events);
+}
+
+#[test]
+fn invalid_recordings_are_rejected() {
+    assert_eq!(
+        parse_recording("0 add\n15 jump 1"),
+        Err(String::from("line 2: unknown event `jump`"))
+    );
+    assert_eq!(
+        parse_recording("0 up"),
+        Err(String::from("line 1: missing index"))
+    );
+}
+// ANCHOR_END: parse
+
+// ANCHOR: test
+#[test]
+fn replay_reaches_same_state() {
+    common::run(|| {
+        let first = TempFile::new("first.txt");
+        let second = TempFile::new("second.txt");
+
+        // Record a session by clicking around
+        let app = TestApp::<App>::launch(Init {
+            record: first.create(),
+            replay: None,
+        });
+        for _ in 0..3 {
+            app.click("Add counter", 0);
+        }
+        app.click("+", 1);
+        app.click("Up", 2);
+        app.click("To Start", 2);
+        app.click("-", 0);
+        app.click("Down", 0);
+        app.click("Remove counter", 0);
+        app.click("Add counter", 0);
+        let recorded = shown_counters(&app);
+        drop(app);
+
+        // Space the events 100 ms apart, so the replay is still running when
+        // the test clicks. The recorded offsets would be only a few microseconds.
+        let recording: Vec<(Duration, Event)> = (1..)
+            .map(|step| Duration::from_millis(100 * step))
+            .zip(first.events().into_iter().map(|(_, event)| event))
+            .collect();
+
+        // Replay it in a new app, which schedules the events at their offsets
+        let replay = TestApp::<App>::launch(Init {
+            record: second.create(),
+            replay: Some(recording.clone()),
+        });
+        // Clicks are ignored while the replay is running
+        assert!(replay.model().replaying > 0);
+        replay.click("Add counter", 0);
+        let context = glib::MainContext::default();
+        while replay.model().replaying > 0 {
+            context.iteration(true);
+        }
+        common::pump();
+
+        assert_eq!(recorded.len(), 3);
+        assert_eq!(shown_counters(&replay), recorded);
+        assert_eq!(replay.model().list.counters, VecDeque::from(recorded));
+
+        // The replayed events are recorded again, in the same order
+        let events = |recording: Vec<(Duration, Event)>| -> Vec<Event> {
+            recording.into_iter().map(|(_, event)| event).collect()
+        };
+        assert_eq!(events(second.events()), events(recording));
+    });
+}
+// ANCHOR_END: test
@@ -0,0 +1,40 @@
+//! Tests the `simple` example by clicking its buttons.
+
+// The example is compiled as part of this test, so its private items are accessible
+include!("../examples/simple.rs");
+
+mod common;
+
+use common::{label_texts, TestApp};
+
+// ANCHOR: test
+#[test]
+fn buttons_change_counter() {
+    common::run(|| {
+        let app = TestApp::<AppModel>::launch(0);
+        assert_eq!(label_texts(app.root()), ["Counter: 0"]);
+
+        app.click("Increment", 0);
+        app.click("Increment", 0);
+        assert_eq!(app.model().counter, 2);
+        assert_eq!(label_texts(app.root()), ["Counter: 2"]);
+
+        app.click("Decrement", 0);
+        assert_eq!(label_texts(app.root()), ["Counter: 1"]);
+    });
+}
+// ANCHOR_END: test
+
+#[test]
+fn counter_wraps_around() {
+    common::run(|| {
+        let app = TestApp::<AppModel>::launch(0);
+
+        app.send(AppMsg::Decrement);
+        assert_eq!(app.model().counter, u8::MAX);
+        assert_eq!(label_texts(app.root()), ["Counter: 255"]);
+
+        app.send(AppMsg::Increment);
+        assert_eq!(label_texts(app.root()), ["Counter: 0"]);
+    });
+}
@@ -0,0 +1,84 @@
+//! Starts the `single_instance` example several times on a private
+//! D-Bus session bus and checks that later invocations are forwarded
+//! to the first one.
+
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+
+mod common;
+
+/// Kills the process when dropped, so nothing is left behind if an assertion fails.
+struct KillOnDrop(Child);
+
+impl Drop for KillOnDrop {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+/// `cargo test` builds the examples next to the directory of the test binary.
+fn example_path(name: &str) -> PathBuf {
+    let mut path = std::env::current_exe().unwrap();
+    path.pop();
+    if path.ends_with("deps") {
+        path.pop();
+    }
+    path.join("examples").join(name)
+}
+
+/// Starts a session bus that is only used by this test and returns its address.
+fn start_bus() -> (KillOnDrop, String) {
+    let mut daemon = Command::new("dbus-daemon")
+        .args(["--session", "--nofork", "--print-address"])
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Could not start dbus-daemon, which the test needs for a private session bus");
+
+    let mut address = String::new();
+    BufReader::new(daemon.stdout.take().unwrap())
+        .read_line(&mut address)
+        .unwrap();
+
+    (KillOnDrop(daemon), address.trim().to_owned())
+}
+
+#[test]
+fn command_lines_are_forwarded_to_primary_instance() {
+    common::require_display();
+
+    let (_bus, address) = start_bus();
+    let example = example_path("single_instance");
+    let invoke = |args: &[&str]| {
+        let mut command = Command::new(&example);
+        command
+            .args(args)
+            .env("DBUS_SESSION_BUS_ADDRESS", &address)
+            .current_dir(env!("CARGO_MANIFEST_DIR"));
+        command
+    };
+
+    let mut primary = KillOnDrop(
+        invoke(&["--message", "first"])
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap(),
+    );
+    let mut output = BufReader::new(primary.0.stdout.take().unwrap()).lines();
+    assert_eq!(output.next().unwrap().unwrap(), "Message: first");
+
+    // Secondary invocations exit once the primary instance handled them
+    assert!(invoke(&["--message", "second"]).status().unwrap().success());
+    assert_eq!(output.next().unwrap().unwrap(), "Message: second");
+
+    assert!(invoke(&["Cargo.toml"]).status().unwrap().success());
+    let expected = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+    assert_eq!(
+        output.next().unwrap().unwrap(),
+        format!("Open: {}", expected.display())
+    );
+
+    assert!(invoke(&["--quit"]).status().unwrap().success());
+    assert!(primary.0.wait().unwrap().success());
+}
@@ -0,0 +1,41 @@
+//! Tests the `tracker` example, whose model changes randomly.
+
+// The example is compiled as part of this test, so its private items are accessible
+include!("../examples/tracker.rs");
+
+mod common;
+
+use common::{find, TestApp};
+use relm4::gtk::prelude::WidgetExt;
+
+/// Returns the icon names of both images.
+fn icon_names(app: &TestApp<AppModel>) -> Vec<String> {
+    find::<gtk::Image>(app.root())
+        .iter()
+        .map(|image| image.icon_name().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn view_matches_model() {
+    common::run(|| {
+        let app = TestApp::<AppModel>::launch(());
+
+        for _ in 0..20 {
+            let before = icon_names(&app);
+
+            app.click("New random image", 0);
+            let after = icon_names(&app);
+            assert_ne!(after[0], before[0]);
+            assert_eq!(after[1], before[1]);
+
+            app.send(AppInput::UpdateSecond);
+            let model = app.model();
+            assert_eq!(icon_names(&app), [model.first_icon, model.second_icon]);
+            assert_eq!(
+                app.root().has_css_class("identical"),
+                model.first_icon == model.second_icon
+            );
+        }
+    });
+}